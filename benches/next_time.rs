@@ -0,0 +1,133 @@
+use std::convert::TryInto;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use cronchik::{CronSchedule, DayMatching};
+use time::macros::datetime;
+
+const EXPRESSIONS: [&str; 6] = [
+    "* * * * *",
+    "*/15 9-17 * * MON-FRI",
+    "0 0 1 * *",
+    "30 4 1,15 * 5",
+    "0 0 29 2 *",
+    "0 12 * 6 SUN",
+];
+
+///Restart loop search, which was used before bit scans, re-written over public API.
+///
+///Supports only plain lists of days, which is enough for expressions above.
+struct RestartLoop {
+    seconds: Vec<u8>,
+    minutes: Vec<u8>,
+    hours: Vec<u8>,
+    days_of_month: Vec<u8>,
+    months: Vec<u8>,
+    days_of_week: Vec<u8>,
+    day_matching: DayMatching,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl RestartLoop {
+    fn new(schedule: &CronSchedule) -> Self {
+        Self {
+            seconds: schedule.seconds().iter().map(Into::into).collect(),
+            minutes: schedule.minutes().iter().map(Into::into).collect(),
+            hours: schedule.hours().iter().map(Into::into).collect(),
+            days_of_month: schedule.days_of_month().days().iter().map(Into::into).collect(),
+            months: schedule.months().iter().map(Into::into).collect(),
+            days_of_week: schedule.days_of_week().days().iter().map(Into::into).collect(),
+            day_matching: schedule.day_matching(),
+            any_day_of_month: schedule.days_of_month().is_any(),
+            any_day_of_week: schedule.days_of_week().is_any(),
+        }
+    }
+
+    fn next_from(values: &[u8], num: u8) -> Option<u8> {
+        match values.binary_search(&num) {
+            Ok(idx) => Some(values[idx]),
+            Err(idx) => values.get(idx).copied(),
+        }
+    }
+
+    fn is_day_allowed(&self, date: time::Date) -> bool {
+        let day_m = self.days_of_month.binary_search(&date.day()).is_ok();
+        let day_w = self.days_of_week.binary_search(&date.weekday().number_days_from_sunday()).is_ok();
+        match self.day_matching {
+            DayMatching::Or if !self.any_day_of_month && !self.any_day_of_week => day_m || day_w,
+            _ => day_m && day_w,
+        }
+    }
+
+    fn next_time_from(&self, time: time::OffsetDateTime) -> time::OffsetDateTime {
+        let offset = time.offset();
+        let mut next = (time + time::Duration::seconds(1)).replace_nanosecond(0).unwrap();
+
+        loop {
+            let month = next.month() as u8;
+            let next_month = Self::next_from(&self.months, month);
+            if next_month != Some(month) {
+                let date = match next_month {
+                    Some(month) => time::Date::from_calendar_date(next.year(), month.try_into().unwrap(), 1).unwrap(),
+                    None => time::Date::from_calendar_date(next.year() + 1, time::Month::January, 1).unwrap(),
+                };
+                next = date.midnight().assume_offset(offset);
+                continue;
+            }
+
+            if !self.is_day_allowed(next.date()) {
+                next = (next.date() + time::Duration::days(1)).midnight().assume_offset(offset);
+                continue;
+            }
+
+            let hour = next.hour();
+            let next_hour = Self::next_from(&self.hours, hour);
+            if next_hour != Some(hour) {
+                next = match next_hour {
+                    Some(hour) => next.date().with_hms(hour, 0, 0).unwrap(),
+                    None => (next.date() + time::Duration::days(1)).midnight(),
+                }.assume_offset(offset);
+                continue;
+            }
+
+            let minute = next.minute();
+            let next_minute = Self::next_from(&self.minutes, minute);
+            if next_minute != Some(minute) {
+                next = match next_minute {
+                    Some(minute) => next.date().with_hms(hour, minute, 0).unwrap().assume_offset(offset),
+                    None => next.date().with_hms(hour, 0, 0).unwrap().assume_offset(offset) + time::Duration::hours(1),
+                };
+                continue;
+            }
+
+            let second = next.second();
+            let next_second = Self::next_from(&self.seconds, second);
+            if next_second != Some(second) {
+                next = match next_second {
+                    Some(second) => next.date().with_hms(hour, minute, second).unwrap().assume_offset(offset),
+                    None => next.date().with_hms(hour, minute, 0).unwrap().assume_offset(offset) + time::Duration::minutes(1),
+                };
+                continue;
+            }
+
+            break next;
+        }
+    }
+}
+
+fn next_time(criterion: &mut Criterion) {
+    let start = datetime!(2021-03-14 17:42:31.5 UTC);
+
+    for expr in EXPRESSIONS {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let restart_loop = RestartLoop::new(&schedule);
+        assert_eq!(schedule.next_time_from(start), restart_loop.next_time_from(start), "'{}'", expr);
+
+        let mut group = criterion.benchmark_group(expr);
+        group.bench_function("bit scan", |bencher| bencher.iter(|| schedule.next_time_from(black_box(start))));
+        group.bench_function("restart loop", |bencher| bencher.iter(|| restart_loop.next_time_from(black_box(start))));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, next_time);
+criterion_main!(benches);
@@ -0,0 +1,259 @@
+//! AWS EventBridge schedule expressions.
+//!
+//!## Syntax
+//!
+//!- `cron(<minutes> <hours> <days of month> <months> <days of week> <years>)` - Refer to [Dialect::Aws];
+//!- `rate(<value> <unit>)` - `value` is positive integer, while `unit` is `minute`, `hour` or `day`.
+//!Unit must be singular when `value` is `1` and plural otherwise (e.g. `rate(1 hour)` and `rate(5 minutes)`).
+//!
+//!In cron expression either day of month or day of week must be `?`.
+//!
+//!## Usage
+//!
+//!```
+//!use cronchik::aws::{AwsSchedule, RateUnit};
+//!
+//!let schedule = AwsSchedule::parse_str("cron(0/15 8-17 ? * MON-FRI *)").unwrap();
+//!assert!(matches!(schedule, AwsSchedule::Cron(_)));
+//!assert_eq!(format!("{}", schedule), "cron(0,15,30,45 8-17 ? * MON-FRI *)");
+//!
+//!let schedule = AwsSchedule::parse_str("rate(5 minutes)").unwrap();
+//!match schedule {
+//!    AwsSchedule::Rate(rate) => {
+//!        assert_eq!(rate.value(), 5);
+//!        assert_eq!(rate.unit(), RateUnit::Minute);
+//!    },
+//!    AwsSchedule::Cron(_) => unreachable!(),
+//!}
+//!```
+
+use core::fmt;
+
+use crate::utils::IteratorExt;
+use crate::{CronSchedule, Dialect, ParseError, ParseOptions};
+
+#[derive(Debug, Copy, Clone)]
+///AWS schedule expression parser error
+pub enum AwsParseError {
+    ///Expression is neither `cron(...)` nor `rate(...)`.
+    InvalidWrapper,
+    ///Indicates invalid cron expression.
+    Cron(ParseError),
+    ///Neither or both of day of month and day of week are `?`.
+    InvalidDays,
+    ///Rate value is not positive integer.
+    InvalidRateValue,
+    ///Rate unit is not `minute`, `hour` or `day`, or its plurality doesn't match value.
+    InvalidRateUnit,
+}
+
+impl fmt::Display for AwsParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidWrapper => fmt.write_str("Expression must be either cron(...) or rate(...)"),
+            Self::Cron(error) => fmt::Display::fmt(error, fmt),
+            Self::InvalidDays => fmt.write_str("Either day of month or day of week must be '?'"),
+            Self::InvalidRateValue => fmt.write_str("Rate value must be positive integer"),
+            Self::InvalidRateUnit => fmt.write_str("Rate unit must be minute(s), hour(s) or day(s)"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AwsParseError {}
+
+impl From<ParseError> for AwsParseError {
+    #[inline(always)]
+    fn from(error: ParseError) -> Self {
+        Self::Cron(error)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Unit of rate expression.
+pub enum RateUnit {
+    ///Minute
+    Minute,
+    ///Hour
+    Hour,
+    ///Day
+    Day,
+}
+
+impl RateUnit {
+    #[inline(always)]
+    ///Returns number of seconds in unit.
+    pub const fn seconds(self) -> u32 {
+        match self {
+            Self::Minute => 60,
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns textual representation of singular unit.
+    pub const fn to_textual_repr(self) -> &'static str {
+        match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Rate expression, that runs every `value` of `unit`, starting from time when rule is created.
+pub struct Rate {
+    value: u32,
+    unit: RateUnit,
+}
+
+impl Rate {
+    ///Creates new rate, returning `None` if `value` is `0`.
+    pub const fn new(value: u32, unit: RateUnit) -> Option<Self> {
+        match value {
+            0 => None,
+            value => Some(Self {
+                value,
+                unit,
+            })
+        }
+    }
+
+    ///Parses rate from `<value> <unit>`.
+    fn from_expr(text: &str) -> Result<Self, AwsParseError> {
+        let [value, unit] = match text.split_whitespace().collect_exact() {
+            Some(fields) => fields,
+            None => return Err(AwsParseError::InvalidRateValue),
+        };
+
+        let value = match value.parse() {
+            Ok(value) if value > 0 => value,
+            _ => return Err(AwsParseError::InvalidRateValue),
+        };
+
+        let unit = match (value, unit) {
+            (1, "minute") | (2.., "minutes") => RateUnit::Minute,
+            (1, "hour") | (2.., "hours") => RateUnit::Hour,
+            (1, "day") | (2.., "days") => RateUnit::Day,
+            _ => return Err(AwsParseError::InvalidRateUnit),
+        };
+
+        Ok(Self {
+            value,
+            unit,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns number of units between invocations.
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    #[inline(always)]
+    ///Returns unit of rate.
+    pub const fn unit(&self) -> RateUnit {
+        self.unit
+    }
+
+    #[cfg(feature = "time")]
+    #[inline(always)]
+    ///Returns interval between invocations.
+    ///
+    ///Available with `time` feature
+    pub fn interval(&self) -> time::Duration {
+        time::Duration::seconds(self.value as i64 * self.unit.seconds() as i64)
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns next point in time, after `time`, for rate started at `start`.
+    ///
+    ///First invocation happens one interval after `start`.
+    ///
+    ///Available with `time` feature
+    pub fn next_time_from(&self, start: time::OffsetDateTime, time: time::OffsetDateTime) -> time::OffsetDateTime {
+        let interval = self.interval().whole_seconds();
+        let elapsed = core::cmp::max((time - start).whole_seconds(), 0);
+        start + time::Duration::seconds((elapsed / interval + 1) * interval)
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = match self.value {
+            1 => "",
+            _ => "s",
+        };
+        fmt.write_fmt(format_args!("rate({} {}{})", self.value, self.unit.to_textual_repr(), plural))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///AWS EventBridge schedule expression.
+pub enum AwsSchedule {
+    ///`cron(...)` expression.
+    Cron(CronSchedule),
+    ///`rate(...)` expression.
+    Rate(Rate),
+}
+
+impl AwsSchedule {
+    ///Parses schedule expression from string.
+    pub fn parse_str(text: &str) -> Result<Self, AwsParseError> {
+        let text = text.trim();
+
+        if let Some(expr) = text.strip_prefix("cron(").and_then(|text| text.strip_suffix(')')) {
+            let schedule = CronSchedule::parse_str_with(expr, ParseOptions::new().dialect(Dialect::Aws))?;
+
+            let mut fields = expr.split_whitespace();
+            let day_m = fields.nth(2);
+            let day_w = fields.nth(1);
+            if (day_m == Some("?")) == (day_w == Some("?")) {
+                return Err(AwsParseError::InvalidDays);
+            }
+
+            Ok(Self::Cron(schedule))
+        } else if let Some(expr) = text.strip_prefix("rate(").and_then(|text| text.strip_suffix(')')) {
+            Rate::from_expr(expr).map(Self::Rate)
+        } else {
+            Err(AwsParseError::InvalidWrapper)
+        }
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns next point in time, after `time`, for schedule created at `start`.
+    ///
+    ///Same as AWS, cron expression is evaluated in UTC and doesn't run before `start`, while rate expression runs every interval since `start`.
+    ///
+    ///Returns `None` if last year allowed by cron expression has passed.
+    ///
+    ///Available with `time` feature
+    pub fn next_time_from(&self, start: time::OffsetDateTime, time: time::OffsetDateTime) -> Option<time::OffsetDateTime> {
+        match self {
+            Self::Cron(schedule) => {
+                let time = core::cmp::max(start, time);
+                schedule.try_next_time_from(time.to_offset(time::UtcOffset::UTC))
+            },
+            Self::Rate(rate) => Some(rate.next_time_from(start, time)),
+        }
+    }
+}
+
+impl fmt::Display for AwsSchedule {
+    ///Writes schedule expression.
+    ///
+    ///AWS requires `?` in either day of month or day of week.
+    ///When [AwsSchedule::Cron] restricts both of them, they are still written as is, but the result is rejected by [AwsSchedule::parse_str].
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cron(schedule) => {
+                fmt.write_str("cron(")?;
+                schedule.write_expr(fmt, Dialect::Aws)?;
+                fmt.write_str(")")
+            },
+            Self::Rate(rate) => fmt::Display::fmt(rate, fmt),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use core::fmt;
+
+use crate::{CronSchedule, DayMatching, Values, Years, YearsMask, DaysOfMonth, DaysOfWeek};
+use crate::{Second, Minute, Hour, DayOfMonth, Month, Day, Year};
+
+#[derive(Debug, Copy, Clone)]
+///Cron schedule builder error.
+pub enum BuildError {
+    ///Field has no values.
+    ///
+    ///### Params:
+    ///
+    ///- `str` - Name of field.
+    EmptyField(&'static str),
+    ///Value is outside of field's allowed range.
+    ///
+    ///### Params:
+    ///
+    ///- `str` - Name of field;
+    ///- `u16` - value.
+    OutOfRange(&'static str, u16),
+    ///Schedule never matches any date, e.g. 30th of February.
+    ImpossibleDate,
+    ///Years are neither evenly spaced nor fit within 64 steps, refer to [Years].
+    SparseYears,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyField(name) => fmt.write_fmt(format_args!("{name}: No values")),
+            Self::OutOfRange(name, value) => fmt.write_fmt(format_args!("{name}: Value {value} is outside of allowed range")),
+            Self::ImpossibleDate => fmt.write_str("Schedule never matches any date"),
+            Self::SparseYears => fmt.write_str("Year: Values are too sparse"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+///Value of field `T`, which is either `T` itself or its number.
+///
+///Allows to pass ranges of numbers, e.g. `9..=17` or `(0..60).step_by(15)`, as well as typed values like `[Day::Monday, Day::Friday]`.
+pub trait FieldValue<T> {
+    ///Returns number of value, which is not yet checked to be within allowed range.
+    fn into_num(self) -> u16;
+}
+
+macro_rules! impl_field_value {
+    ($($ty:ident: $num:ty;)+) => {
+        $(
+            impl FieldValue<$ty> for $ty {
+                #[inline(always)]
+                fn into_num(self) -> u16 {
+                    let num: $num = self.into();
+                    num as u16
+                }
+            }
+
+            impl FieldValue<$ty> for $num {
+                #[inline(always)]
+                fn into_num(self) -> u16 {
+                    self as u16
+                }
+            }
+        )+
+    };
+}
+
+impl_field_value!(
+    Second: u8;
+    Minute: u8;
+    Hour: u8;
+    DayOfMonth: u8;
+    Month: u8;
+    Day: u8;
+    Year: u16;
+);
+
+///Collects numbers of field's values, checking that they are within `min..=max` and that there is at least one.
+fn collect<T, V: FieldValue<T>>(name: &'static str, min: u16, max: u16, values: impl IntoIterator<Item = V>, mut insert: impl FnMut(u16)) -> Result<(), BuildError> {
+    let mut is_empty = true;
+    for value in values {
+        let num = value.into_num();
+        if num < min || num > max {
+            return Err(BuildError::OutOfRange(name, num));
+        }
+        insert(num);
+        is_empty = false;
+    }
+
+    match is_empty {
+        true => Err(BuildError::EmptyField(name)),
+        false => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone)]
+///Builder of [CronSchedule] out of field values, as alternative to formatting expression.
+///
+///Initially schedule matches start of every minute, as `* * * * *`, while each method replaces values of single field.
+///Only exact values are supported, i.e. there is no way to specify `L`, `W` or `#`.
+///
+///```
+///use cronchik::{CronSchedule, Day};
+///
+///let schedule = CronSchedule::builder().minutes((0..60).step_by(15))
+///                                      .hours(9..=17)
+///                                      .days_of_week([Day::Monday, Day::Wednesday, Day::Friday])
+///                                      .build()
+///                                      .unwrap();
+///assert_eq!(schedule, CronSchedule::parse_str("*/15 9-17 * * MON,WED,FRI").unwrap());
+///
+///assert!(CronSchedule::builder().hours(24..).build().is_err());
+///```
+pub struct CronScheduleBuilder {
+    schedule: CronSchedule,
+    //First error, which is reported on build.
+    error: Option<BuildError>,
+}
+
+impl CronScheduleBuilder {
+    ///Creates builder of schedule, matching start of every minute.
+    pub const fn new() -> Self {
+        Self {
+            schedule: CronSchedule::EVERY_MINUTE,
+            error: None,
+        }
+    }
+
+    ///Collects values of field `T`, remembering first error.
+    fn values<T, V: FieldValue<T>>(&mut self, name: &'static str, min: u8, max: u8, values: impl IntoIterator<Item = V>) -> Option<Values<T>> {
+        let mut result = Values::new();
+        match collect(name, min.into(), max.into(), values, |num| result.insert(num as u8)) {
+            Ok(()) => Some(result),
+            Err(error) => {
+                self.error.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    ///Specifies seconds, which are `0` by default.
+    pub fn seconds<V: FieldValue<Second>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(Second::NAME, Second::MIN, Second::MAX, values) {
+            self.schedule.second = values.bits();
+        }
+        self
+    }
+
+    ///Specifies minutes.
+    pub fn minutes<V: FieldValue<Minute>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(Minute::NAME, Minute::MIN, Minute::MAX, values) {
+            self.schedule.minute = values.bits();
+        }
+        self
+    }
+
+    ///Specifies hours.
+    pub fn hours<V: FieldValue<Hour>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(Hour::NAME, Hour::MIN, Hour::MAX, values) {
+            self.schedule.hour = values.bits() as u32;
+        }
+        self
+    }
+
+    ///Specifies days of month.
+    pub fn days_of_month<V: FieldValue<DayOfMonth>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(DayOfMonth::NAME, DayOfMonth::MIN, DayOfMonth::MAX, values) {
+            self.schedule.day_m = DaysOfMonth::from_days(values);
+        }
+        self
+    }
+
+    ///Specifies months.
+    pub fn months<V: FieldValue<Month>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(Month::NAME, Month::MIN, Month::MAX, values) {
+            self.schedule.month = values.bits() as u16;
+        }
+        self
+    }
+
+    ///Specifies days of week, numbered from `0` (Sunday) to `6` (Saturday).
+    pub fn days_of_week<V: FieldValue<Day>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        if let Some(values) = self.values(Day::NAME, Day::MIN, Day::MAX, values) {
+            self.schedule.day_w = DaysOfWeek::from_days(values);
+        }
+        self
+    }
+
+    ///Specifies years, which are not restricted by default.
+    pub fn years<V: FieldValue<Year>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        let mut years = YearsMask::new();
+        let result = collect(Year::NAME, Year::MIN, Year::MAX, values, |num| years.insert(num)).and_then(|_| Years::from_mask(&years).ok_or(BuildError::SparseYears));
+        match result {
+            //Every year is stored as unrestricted, same as parsed `*`.
+            Ok(years) if years.is_all() => self.schedule.year = Years::new(),
+            Ok(years) => self.schedule.year = years,
+            Err(error) => {
+                self.error.get_or_insert(error);
+            }
+        }
+        self
+    }
+
+    #[inline(always)]
+    ///Specifies how day of month and day of week are combined, which is [DayMatching::Or] by default.
+    pub fn day_matching(mut self, day_matching: DayMatching) -> Self {
+        self.schedule.day_matching = day_matching;
+        self
+    }
+
+    ///Creates schedule, failing on first invalid field or if schedule never matches any date.
+    pub fn build(self) -> Result<CronSchedule, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        match self.schedule.has_date() {
+            true => Ok(self.schedule),
+            false => Err(BuildError::ImpossibleDate),
+        }
+    }
+}
+
+impl Default for CronScheduleBuilder {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,257 @@
+use core::fmt::{self, Write};
+
+use crate::utils::{BitIter, Runs, Step};
+use crate::{CronSchedule, Second, Minute, Hour, DayOfMonth, Month, Day, Year};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes how months and days of week are written.
+pub enum Names {
+    ///Numbers, like `1-5` or `6`.
+    Numeric,
+    ///Textual representation, like `MON-FRI` or `JUN`.
+    Textual,
+}
+
+///Writes single value of field.
+type WriteValue = fn(&mut dyn Write, u16, Names) -> fmt::Result;
+
+fn write_num(out: &mut dyn Write, value: u16, _: Names) -> fmt::Result {
+    write!(out, "{}", value)
+}
+
+fn write_month(out: &mut dyn Write, value: u16, names: Names) -> fmt::Result {
+    match names {
+        Names::Numeric => write_num(out, value, names),
+        Names::Textual => out.write_str(Month::from_num_asserted(value as u8).to_textual_repr()),
+    }
+}
+
+fn write_day(out: &mut dyn Write, value: u16, names: Names) -> fmt::Result {
+    match names {
+        Names::Numeric => write_num(out, value, names),
+        Names::Textual => out.write_str(Day::from_num_asserted(value as u8).to_textual_repr()),
+    }
+}
+
+///Counts length of written text.
+struct Len(usize);
+
+impl Write for Len {
+    #[inline(always)]
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.0 += text.len();
+        Ok(())
+    }
+}
+
+///Range of field's values along with the way to write them.
+struct Field {
+    min: u16,
+    max: u16,
+    write_value: WriteValue,
+}
+
+const SECOND: Field = Field {
+    min: Second::MIN as u16,
+    max: Second::MAX as u16,
+    write_value: write_num,
+};
+
+const MINUTE: Field = Field {
+    min: Minute::MIN as u16,
+    max: Minute::MAX as u16,
+    write_value: write_num,
+};
+
+const HOUR: Field = Field {
+    min: Hour::MIN as u16,
+    max: Hour::MAX as u16,
+    write_value: write_num,
+};
+
+const DAY_OF_MONTH: Field = Field {
+    min: DayOfMonth::MIN as u16,
+    max: DayOfMonth::MAX as u16,
+    write_value: write_num,
+};
+
+const MONTH: Field = Field {
+    min: Month::MIN as u16,
+    max: Month::MAX as u16,
+    write_value: write_month,
+};
+
+const DAY: Field = Field {
+    min: Day::MIN as u16,
+    max: Day::MAX as u16,
+    write_value: write_day,
+};
+
+const YEAR: Field = Field {
+    min: Year::MIN,
+    max: Year::MAX,
+    write_value: write_num,
+};
+
+impl Field {
+    ///Writes runs of values as list, like `a-b,c`.
+    fn write_list(&self, out: &mut dyn Write, values: impl Iterator<Item = u16>, names: Names) -> fmt::Result {
+        for (idx, (first, last)) in Runs::new(values).enumerate() {
+            if idx > 0 {
+                out.write_str(",")?;
+            }
+            (self.write_value)(out, first, names)?;
+            if first != last {
+                out.write_str("-")?;
+                (self.write_value)(out, last, names)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///Writes step as `*/n`, `a/n` or `a-b/n`.
+    fn write_step(&self, out: &mut dyn Write, step: Step, names: Names) -> fmt::Result {
+        if step.is_full(self.min, self.max) {
+            out.write_str("*")?;
+        } else {
+            (self.write_value)(out, step.first, names)?;
+            //Without end step continues up to max value.
+            if step.last + step.step <= self.max {
+                out.write_str("-")?;
+                (self.write_value)(out, step.last, names)?;
+            }
+        }
+        write!(out, "/{}", step.step)
+    }
+
+    ///Writes values in the shortest form, preferring list over step of the same length.
+    ///
+    ///`*` is written only if `is_any_allowed`, as it cannot be combined with other items.
+    fn write_values(&self, out: &mut dyn Write, values: impl Iterator<Item = u16> + Clone, names: Names, is_any_allowed: bool) -> fmt::Result {
+        if is_any_allowed && values.clone().count() == (self.max - self.min + 1) as usize {
+            return out.write_str("*");
+        }
+
+        if let Some(step) = Step::detect(values.clone()) {
+            let mut step_len = Len(0);
+            self.write_step(&mut step_len, step, names)?;
+            let mut list_len = Len(0);
+            self.write_list(&mut list_len, values.clone(), names)?;
+            if step_len.0 < list_len.0 {
+                return self.write_step(out, step, names);
+            }
+        }
+
+        self.write_list(out, values, names)
+    }
+}
+
+#[inline(always)]
+fn bit_values(bits: u64) -> impl Iterator<Item = u16> + Clone {
+    BitIter(bits).map(u16::from)
+}
+
+///Writes comma unless it is the first item.
+fn write_separator(out: &mut dyn Write, is_first: &mut bool) -> fmt::Result {
+    match core::mem::replace(is_first, false) {
+        true => Ok(()),
+        false => out.write_str(","),
+    }
+}
+
+///Wrapper to display [CronSchedule] as the shortest cron expression.
+///
+///Each field is written either as list of values or as step (`*/n`, `a/n` or `a-b/n`), whichever is shorter.
+///Output is the same for all equivalent expressions.
+///
+///```
+///use cronchik::{CronSchedule, Names};
+///
+///let schedule = CronSchedule::parse_str("0,15,30,45 */2 * 1,4,7,10 MON,TUE,WED,THU,FRI").unwrap();
+///assert_eq!(schedule.display_canonical(Names::Textual).to_string(), "*/15 */2 * */3 MON-FRI");
+///assert_eq!(schedule.display_canonical(Names::Numeric).to_string(), "*/15 */2 * */3 1-5");
+///```
+pub struct CanonicalDisplay<'a> {
+    pub(crate) schedule: &'a CronSchedule,
+    pub(crate) names: Names,
+}
+
+impl fmt::Display for CanonicalDisplay<'_> {
+    ///Writes canonical cron expression.
+    ///
+    ///With alternate flag (`{:#}`) nickname is written instead when schedule matches it exactly.
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let schedule = self.schedule;
+        let names = self.names;
+        if fmt.alternate() {
+            if let Some(nickname) = schedule.nickname() {
+                return fmt.write_str(nickname);
+            }
+        }
+
+        //Seconds are omitted when they match default of 5 fields expression, unless year is present.
+        if !schedule.year.is_empty() || schedule.second != 1 {
+            SECOND.write_values(fmt, bit_values(schedule.second), names, true)?;
+            fmt.write_str(" ")?;
+        }
+        MINUTE.write_values(fmt, bit_values(schedule.minute), names, true)?;
+        fmt.write_str(" ")?;
+        HOUR.write_values(fmt, bit_values(schedule.hour as u64), names, true)?;
+        fmt.write_str(" ")?;
+
+        let day_m = &schedule.day_m;
+        if day_m.is_any() {
+            fmt.write_str("*")?;
+        } else {
+            let days = day_m.days();
+            DAY_OF_MONTH.write_values(fmt, bit_values(days.bits()), names, false)?;
+            let mut is_first = days.is_empty();
+            for offset in day_m.last_day_offsets() {
+                write_separator(fmt, &mut is_first)?;
+                match offset {
+                    0 => fmt.write_str("L")?,
+                    offset => write!(fmt, "L-{}", offset)?,
+                }
+            }
+            for nearest in day_m.nearest_weekdays() {
+                write_separator(fmt, &mut is_first)?;
+                write!(fmt, "{}W", nearest)?;
+            }
+            if day_m.last_weekday() {
+                write_separator(fmt, &mut is_first)?;
+                fmt.write_str("LW")?;
+            }
+        }
+        fmt.write_str(" ")?;
+
+        MONTH.write_values(fmt, bit_values(schedule.month as u64), names, true)?;
+        fmt.write_str(" ")?;
+
+        let day_w = &schedule.day_w;
+        if day_w.is_any() {
+            fmt.write_str("*")?;
+        } else {
+            let days = day_w.days();
+            DAY.write_values(fmt, bit_values(days.bits()), names, false)?;
+            let mut is_first = days.is_empty();
+            for (day, num) in day_w.nth_days() {
+                write_separator(fmt, &mut is_first)?;
+                write_day(fmt, day as u16, names)?;
+                write!(fmt, "#{}", num)?;
+            }
+            for day in day_w.last_days() {
+                write_separator(fmt, &mut is_first)?;
+                write_day(fmt, day as u16, names)?;
+                fmt.write_str("L")?;
+            }
+        }
+
+        if !schedule.year.is_empty() {
+            fmt.write_str(" ")?;
+            YEAR.write_values(fmt, schedule.year.iter().map(Into::<u16>::into), names, true)?;
+        }
+
+        Ok(())
+    }
+}
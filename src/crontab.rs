@@ -0,0 +1,336 @@
+//! Crontab file parser.
+//!
+//!## Syntax
+//!
+//!Crontab is parsed line by line, where each line is one of:
+//!
+//!- Blank line;
+//!- Comment, starting with `#`;
+//!- Environment assignment `<name>=<value>`, where spaces around `=` are ignored and value can be enclosed in single or double quotes;
+//!- Entry `<minutes> <hours> <days of month> <months> <days of week> <command>` or `@<nickname> <command>`.
+//!
+//!In system crontab format (e.g. `/etc/crontab`) entry has user column before command.
+//!
+//!Only nicknames described in [crate documentation](../index.html) are supported, hence `@reboot` results in [ParseError::Unsupported].
+//!
+//!## Usage
+//!
+//!```
+//!use cronchik::crontab::{CrontabParser, Line};
+//!
+//!let text = "# Backup\nMAILTO=\"ops@example.com\"\n\n30 2 * * * /usr/bin/backup --full\n";
+//!let mut lines = CrontabParser::new(text);
+//!
+//!assert!(matches!(lines.next(), Some(Ok(Line::Comment(" Backup")))));
+//!assert!(matches!(lines.next(), Some(Ok(Line::Env("MAILTO", "ops@example.com")))));
+//!assert!(matches!(lines.next(), Some(Ok(Line::Blank))));
+//!match lines.next() {
+//!    Some(Ok(Line::Entry(entry))) => {
+//!        assert_eq!(format!("{}", entry.schedule()), "30 2 * * *");
+//!        assert_eq!(entry.command(), "/usr/bin/backup --full");
+//!    },
+//!    _ => unreachable!(),
+//!}
+//!assert!(lines.next().is_none());
+//!```
+
+use core::fmt;
+
+use crate::{CronSchedule, ParseError, ParseOptions};
+use crate::{Minute, Hour, DayOfMonth, Month, Day};
+
+//Order of fields in entry's schedule.
+const FIELDS: [&'static str; 5] = [Minute::NAME, Hour::NAME, DayOfMonth::NAME, Month::NAME, Day::NAME];
+
+#[derive(Debug, Copy, Clone)]
+///Describes error within crontab line.
+pub enum CrontabErrorKind {
+    ///Indicates invalid schedule of entry.
+    Cron(ParseError),
+    ///Entry has no user column in system crontab format.
+    MissingUser,
+    ///Entry has no command.
+    MissingCommand,
+    ///Environment assignment has unterminated quoted value.
+    UnterminatedQuote,
+}
+
+#[derive(Debug, Copy, Clone)]
+///Crontab parser error.
+pub struct CrontabError {
+    line: usize,
+    column: usize,
+    kind: CrontabErrorKind,
+}
+
+impl CrontabError {
+    #[inline(always)]
+    ///Returns line number, starting from `1`.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    #[inline(always)]
+    ///Returns column (i.e. byte offset within line), starting from `1`.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    #[inline(always)]
+    ///Returns description of error.
+    pub fn kind(&self) -> CrontabErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for CrontabError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("{}:{}: ", self.line, self.column))?;
+        match self.kind {
+            CrontabErrorKind::Cron(error) => fmt::Display::fmt(&error, fmt),
+            CrontabErrorKind::MissingUser => fmt.write_str("Missing user"),
+            CrontabErrorKind::MissingCommand => fmt.write_str("Missing command"),
+            CrontabErrorKind::UnterminatedQuote => fmt.write_str("Unterminated quoted value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CrontabError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes layout of crontab entry.
+pub enum CrontabFormat {
+    ///User crontab, as edited by `crontab -e`: `<schedule> <command>`.
+    User,
+    ///System crontab, like `/etc/crontab`: `<schedule> <user> <command>`.
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Crontab entry.
+pub struct Entry<'a> {
+    schedule: CronSchedule,
+    user: Option<&'a str>,
+    command: &'a str,
+}
+
+impl<'a> Entry<'a> {
+    #[inline(always)]
+    ///Returns schedule of entry.
+    pub fn schedule(&self) -> &CronSchedule {
+        &self.schedule
+    }
+
+    #[inline(always)]
+    ///Returns user to run command as, which is present only in [CrontabFormat::System].
+    pub fn user(&self) -> Option<&'a str> {
+        self.user
+    }
+
+    #[inline(always)]
+    ///Returns command as it is written, without trailing whitespaces.
+    pub fn command(&self) -> &'a str {
+        self.command
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Crontab line.
+pub enum Line<'a> {
+    ///Blank line.
+    Blank,
+    ///Comment, without leading `#`.
+    Comment(&'a str),
+    ///Environment assignment with name and unquoted value.
+    Env(&'a str, &'a str),
+    ///Scheduled command.
+    Entry(Entry<'a>),
+}
+
+///Iterator over parsed lines of crontab.
+pub struct CrontabParser<'a> {
+    lines: core::str::Lines<'a>,
+    line: usize,
+    format: CrontabFormat,
+    options: ParseOptions,
+}
+
+impl<'a> CrontabParser<'a> {
+    #[inline]
+    ///Creates parser of user crontab.
+    pub fn new(text: &'a str) -> Self {
+        Self::with_format(text, CrontabFormat::User)
+    }
+
+    #[inline]
+    ///Creates parser of crontab in specified `format`.
+    pub fn with_format(text: &'a str, format: CrontabFormat) -> Self {
+        Self {
+            lines: text.lines(),
+            line: 0,
+            format,
+            options: ParseOptions::new(),
+        }
+    }
+
+    #[inline(always)]
+    ///Specifies options to parse schedules with.
+    pub fn options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[inline(always)]
+    ///Creates error, pointing at start of `at`, which is sub-slice of line's `text`.
+    fn error(&self, text: &str, at: &str, kind: CrontabErrorKind) -> CrontabError {
+        CrontabError {
+            line: self.line,
+            column: at.as_ptr() as usize - text.as_ptr() as usize + 1,
+            kind,
+        }
+    }
+
+    ///Parses `name=value`, returning `None` if line is not environment assignment.
+    fn parse_env(&self, text: &'a str) -> Option<Result<Line<'a>, CrontabError>> {
+        let line = text.trim_start();
+        let name_len = line.find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))?;
+        let (name, rest) = line.split_at(name_len);
+        if !name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_') {
+            return None;
+        }
+        let value = rest.trim_start().strip_prefix('=')?.trim();
+
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => match value[1..].strip_suffix(quote) {
+                Some(value) => value,
+                None => return Some(Err(self.error(text, value, CrontabErrorKind::UnterminatedQuote))),
+            },
+            _ => value,
+        };
+
+        Some(Ok(Line::Env(name, value)))
+    }
+
+    ///Parses entry, which is not comment, blank line or environment assignment.
+    fn parse_entry(&self, text: &'a str) -> Result<Line<'a>, CrontabError> {
+        //Splits next whitespace separated field, returning it with the rest of line.
+        fn split_field(text: &str) -> (&str, &str) {
+            let text = text.trim_start();
+            text.split_at(text.find(char::is_whitespace).unwrap_or(text.len()))
+        }
+
+        let line = text.trim_start();
+        let (schedule, rest) = match line.starts_with('@') {
+            true => split_field(line),
+            false => {
+                let mut rest = line;
+                for _ in 0..FIELDS.len() {
+                    rest = split_field(rest).1;
+                }
+                line.split_at(line.len() - rest.len())
+            },
+        };
+
+        let schedule = match CronSchedule::parse_str_with(schedule, self.options) {
+            Ok(schedule) => schedule,
+            Err(error) => {
+                //Point at failed field, if it is known.
+                let mut column = schedule;
+                if let ParseError::InvalidExpr(name, _) = error {
+                    for _ in FIELDS.iter().take_while(|field| **field != name) {
+                        column = split_field(column).1;
+                    }
+                }
+                return Err(self.error(text, column.trim_start(), CrontabErrorKind::Cron(error)));
+            }
+        };
+
+        let (user, rest) = match self.format {
+            CrontabFormat::User => (None, rest),
+            CrontabFormat::System => match split_field(rest) {
+                ("", rest) => return Err(self.error(text, rest, CrontabErrorKind::MissingUser)),
+                (user, rest) => (Some(user), rest),
+            },
+        };
+
+        match rest.trim() {
+            "" => Err(self.error(text, &text[text.len()..], CrontabErrorKind::MissingCommand)),
+            command => Ok(Line::Entry(Entry {
+                schedule,
+                user,
+                command,
+            })),
+        }
+    }
+}
+
+impl<'a> Iterator for CrontabParser<'a> {
+    type Item = Result<Line<'a>, CrontabError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.line += 1;
+
+        let line = text.trim();
+        if line.is_empty() {
+            Some(Ok(Line::Blank))
+        } else if let Some(comment) = line.strip_prefix('#') {
+            Some(Ok(Line::Comment(comment)))
+        } else if let Some(env) = self.parse_env(text) {
+            Some(env)
+        } else {
+            Some(self.parse_entry(text))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Parsed crontab file.
+///
+///Available with `std` feature.
+pub struct Crontab<'a> {
+    lines: std::vec::Vec<Line<'a>>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Crontab<'a> {
+    #[inline]
+    ///Parses user crontab, failing on first invalid line.
+    pub fn parse_str(text: &'a str) -> Result<Self, CrontabError> {
+        Self::parse_str_with(text, CrontabFormat::User)
+    }
+
+    #[inline]
+    ///Parses crontab in specified `format`, failing on first invalid line.
+    pub fn parse_str_with(text: &'a str, format: CrontabFormat) -> Result<Self, CrontabError> {
+        CrontabParser::with_format(text, format).collect::<Result<_, _>>().map(|lines| Self {
+            lines
+        })
+    }
+
+    #[inline(always)]
+    ///Returns all lines of crontab.
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    #[inline]
+    ///Returns iterator over entries.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry<'a>> + '_ {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Entry(entry) => Some(entry),
+            _ => None,
+        })
+    }
+
+    #[inline]
+    ///Returns iterator over environment assignments as `(name, value)`.
+    pub fn env(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Env(name, value) => Some((*name, *value)),
+            _ => None,
+        })
+    }
+}
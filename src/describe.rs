@@ -0,0 +1,377 @@
+use core::fmt::{self, Write};
+use core::iter;
+
+use crate::utils::{BitIter, Runs, Step};
+use crate::{CronSchedule, DayMatching, Day, Month};
+
+const ORDINALS: [&'static str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+///Field of time of the day.
+struct Unit {
+    name: &'static str,
+    parent: &'static str,
+    min: u16,
+    max: u16,
+}
+
+const SECOND: Unit = Unit {
+    name: "second",
+    parent: "minute",
+    min: 0,
+    max: 59,
+};
+
+const MINUTE: Unit = Unit {
+    name: "minute",
+    parent: "hour",
+    min: 0,
+    max: 59,
+};
+
+///Writes items separated by comma, with `and` before the last one.
+fn write_list<W: Write, T>(out: &mut W, items: impl Iterator<Item = T> + Clone, mut write_item: impl FnMut(&mut W, T) -> fmt::Result) -> fmt::Result {
+    let len = items.clone().count();
+    for (idx, item) in items.enumerate() {
+        match idx {
+            0 => (),
+            idx if idx + 1 == len => out.write_str(" and ")?,
+            _ => out.write_str(", ")?,
+        }
+        write_item(out, item)?;
+    }
+
+    Ok(())
+}
+
+///Writes runs of values as `a` or `a through b`, using `write_value`.
+fn write_runs<W: Write>(out: &mut W, values: impl Iterator<Item = u16> + Clone, write_value: impl Fn(&mut W, u16) -> fmt::Result) -> fmt::Result {
+    write_list(out, Runs::new(values), |out, (first, last)| {
+        write_value(out, first)?;
+        if first != last {
+            out.write_str(" through ")?;
+            write_value(out, last)?;
+        }
+        Ok(())
+    })
+}
+
+#[inline]
+///Detects step of at least 3 values, as fewer are better described by list.
+fn detect_step(values: impl Iterator<Item = u16>) -> Option<Step> {
+    Step::detect(values).filter(|step| step.len() > 2)
+}
+
+#[inline(always)]
+fn bit_values(bits: u64) -> impl Iterator<Item = u16> + Clone {
+    BitIter(bits).map(u16::from)
+}
+
+#[inline(always)]
+fn write_num<W: Write>(out: &mut W, num: u16) -> fmt::Result {
+    write!(out, "{}", num)
+}
+
+///Writes number with ordinal suffix, e.g. `2nd` or `11th`.
+fn write_ordinal<W: Write>(out: &mut W, num: u16) -> fmt::Result {
+    let suffix = match (num % 10, num % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    write!(out, "{}{}", num, suffix)
+}
+
+#[inline(always)]
+fn write_day<W: Write>(out: &mut W, day: u16) -> fmt::Result {
+    out.write_str(Day::from_num_asserted(day as u8).to_name())
+}
+
+#[inline(always)]
+fn write_month<W: Write>(out: &mut W, month: u16) -> fmt::Result {
+    out.write_str(Month::from_num_asserted(month as u8).to_name())
+}
+
+///Writes comma separated clauses, starting with capital letter.
+struct Sentence<'a, 'b> {
+    fmt: &'a mut fmt::Formatter<'b>,
+    is_empty: bool,
+    is_start: bool,
+}
+
+impl Sentence<'_, '_> {
+    ///Starts new clause.
+    fn clause(&mut self) -> Result<&mut Self, fmt::Error> {
+        if !self.is_empty {
+            self.fmt.write_str(", ")?;
+        }
+        self.is_empty = false;
+        Ok(self)
+    }
+}
+
+impl Write for Sentence<'_, '_> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(first) if self.is_start => {
+                self.is_start = false;
+                self.fmt.write_char(first.to_ascii_uppercase())?;
+                self.fmt.write_str(chars.as_str())
+            },
+            _ => self.fmt.write_str(text),
+        }
+    }
+}
+
+///Wrapper to describe [CronSchedule] in English.
+///
+///```
+///use cronchik::CronSchedule;
+///
+///let schedule = CronSchedule::parse_str("5 9-17 * * MON-FRI").unwrap();
+///assert_eq!(schedule.describe().to_string(), "At 5 minutes past the hour, between 09:00 and 17:59, Monday through Friday");
+///```
+pub struct Describe<'a>(pub(crate) &'a CronSchedule);
+
+impl Describe<'_> {
+    ///Writes `every n seconds` or list of values in `unit` (seconds or minutes).
+    fn write_unit(out: &mut Sentence<'_, '_>, bits: u64, unit: &Unit) -> fmt::Result {
+        if let Some(step) = detect_step(bit_values(bits)) {
+            write!(out.clause()?, "every {} {}s", step.step, unit.name)?;
+            if step.last + step.step <= unit.max {
+                write!(out.clause()?, "{}s {} through {} past the {}", unit.name, step.first, step.last, unit.parent)?;
+            } else if step.first != unit.min {
+                write!(out.clause()?, "starting at {} {}s past the {}", step.first, unit.name, unit.parent)?;
+            }
+            return Ok(());
+        }
+
+        out.clause()?.write_str("at ")?;
+        write_runs(out, bit_values(bits), write_num)?;
+        match bits == 1 << 1 {
+            true => write!(out, " {} past the {}", unit.name, unit.parent),
+            false => write!(out, " {}s past the {}", unit.name, unit.parent),
+        }
+    }
+
+    fn write_hours(out: &mut Sentence<'_, '_>, bits: u64) -> fmt::Result {
+        if let Some(step) = detect_step(bit_values(bits)) {
+            write!(out.clause()?, "every {} hours", step.step)?;
+            if !step.is_full(0, 23) {
+                write!(out.clause()?, "between {:02}:00 and {:02}:59", step.first, step.last)?;
+            }
+            return Ok(());
+        }
+
+        write_list(out.clause()?, Runs::new(bit_values(bits)), |out, (first, last)| {
+            write!(out, "between {:02}:00 and {:02}:59", first, last)
+        })
+    }
+
+    fn write_time(&self, out: &mut Sentence<'_, '_>) -> fmt::Result {
+        let schedule = self.0;
+        let seconds = schedule.seconds().bits();
+        let minutes = schedule.minutes().bits();
+        let hours = schedule.hours();
+
+        //Exact times of the day, like `At 09:30 and 18:30`, unless hours are better described as range or step.
+        let is_hours_list = !hours.is_all() && Runs::new(bit_values(hours.bits())).all(|(first, last)| last - first < 2) && detect_step(bit_values(hours.bits())).is_none();
+        if seconds.count_ones() == 1 && minutes.count_ones() == 1 && is_hours_list {
+            let second = seconds.trailing_zeros();
+            let minute = minutes.trailing_zeros();
+            out.clause()?.write_str("at ")?;
+            return write_list(out, BitIter(hours.bits()), |out, hour| {
+                write!(out, "{:02}:{:02}", hour, minute)?;
+                match second {
+                    0 => Ok(()),
+                    second => write!(out, ":{:02}", second),
+                }
+            });
+        }
+
+        //Only zero second is not worth mentioning.
+        let is_zero_second = seconds == 1;
+        if schedule.seconds().is_all() {
+            out.clause()?.write_str("every second")?;
+        } else if !is_zero_second {
+            Self::write_unit(out, seconds, &SECOND)?;
+        }
+
+        if schedule.minutes().is_all() {
+            if is_zero_second {
+                out.clause()?.write_str("every minute")?;
+            }
+        } else if is_zero_second && minutes == 1 && hours.is_all() {
+            return out.clause()?.write_str("every hour");
+        } else if let (true, Some(step)) = (is_zero_second && minutes == 1, detect_step(bit_values(hours.bits()))) {
+            write!(out.clause()?, "every {} hours", step.step)?;
+            if !step.is_full(0, 23) {
+                write!(out.clause()?, "between {:02}:00 and {:02}:00", step.first, step.last)?;
+            }
+            return Ok(());
+        } else {
+            Self::write_unit(out, minutes, &MINUTE)?;
+        }
+
+        match hours.is_all() {
+            true => Ok(()),
+            false => Self::write_hours(out, hours.bits()),
+        }
+    }
+
+    fn write_days_of_month<W: Write>(&self, out: &mut W) -> fmt::Result {
+        #[derive(Clone, Copy)]
+        enum Item {
+            Days,
+            LastDay(u8),
+            Nearest(u8),
+            LastWeekday,
+        }
+
+        let day_m = self.0.days_of_month();
+        let days = day_m.days();
+        let has_days_only = day_m.last_day_offsets().len() == 0 && day_m.nearest_weekdays().is_empty() && !day_m.last_weekday();
+        if has_days_only {
+            //Step restarts every month, hence it is not `every n days`.
+            if let Some(step) = detect_step(bit_values(days.bits())) {
+                out.write_str("every ")?;
+                write_ordinal(out, step.step)?;
+                return write!(out, " day of the month, from day {} through {}", step.first, step.last);
+            }
+        }
+
+        let items = iter::once(Item::Days).filter(|_| !days.is_empty())
+                                          .chain(day_m.last_day_offsets().map(Item::LastDay))
+                                          .chain(day_m.nearest_weekdays().iter().map(|day| Item::Nearest(day.into())))
+                                          .chain(iter::once(Item::LastWeekday).filter(|_| day_m.last_weekday()));
+
+        out.write_str("on ")?;
+        write_list(out, items, |out, item| match item {
+            Item::Days if days.len() == 1 => write!(out, "day {}", days.bits().trailing_zeros()),
+            Item::Days => {
+                out.write_str("days ")?;
+                write_runs(out, bit_values(days.bits()), write_num)
+            },
+            Item::LastDay(0) => out.write_str("the last day"),
+            Item::LastDay(1) => out.write_str("1 day before the last day"),
+            Item::LastDay(offset) => write!(out, "{} days before the last day", offset),
+            Item::Nearest(day) => write!(out, "the weekday nearest day {}", day),
+            Item::LastWeekday => out.write_str("the last weekday"),
+        })?;
+        out.write_str(" of the month")
+    }
+
+    ///Writes days of week, using shorter form for plain days when `is_alone`.
+    fn write_days_of_week<W: Write>(&self, out: &mut W, is_alone: bool) -> fmt::Result {
+        #[derive(Clone, Copy)]
+        enum Item {
+            Days(u16, u16),
+            Nth(Day, u8),
+            Last(Day),
+        }
+
+        let day_w = self.0.days_of_week();
+        let days = bit_values(day_w.days().bits());
+        let has_days_only = day_w.nth_days().next().is_none() && day_w.last_days().is_empty();
+        if has_days_only && is_alone {
+            let mut runs = Runs::new(days.clone());
+            match (runs.next(), runs.next()) {
+                (Some((first, last)), None) if first == last => {
+                    out.write_str("only on ")?;
+                    return write_day(out, first);
+                },
+                (Some((first, last)), None) => {
+                    write_day(out, first)?;
+                    out.write_str(" through ")?;
+                    return write_day(out, last);
+                },
+                _ => (),
+            }
+        }
+
+        let items = Runs::new(days).map(|(first, last)| Item::Days(first, last))
+                                   .chain(day_w.nth_days().map(|(day, num)| Item::Nth(day, num)))
+                                   .chain(day_w.last_days().iter().map(Item::Last));
+
+        out.write_str("on ")?;
+        write_list(out, items, |out, item| match item {
+            Item::Days(first, last) => {
+                write_day(out, first)?;
+                if first != last {
+                    out.write_str(" through ")?;
+                    write_day(out, last)?;
+                }
+                Ok(())
+            },
+            Item::Nth(day, num) => write!(out, "the {} {} of the month", ORDINALS[num as usize - 1], day.to_name()),
+            Item::Last(day) => write!(out, "the last {} of the month", day.to_name()),
+        })
+    }
+
+    ///Writes months or years, using `write_value` for each one.
+    fn write_period<W: Write>(out: &mut W, values: impl Iterator<Item = u16> + Clone, min: u16, max: u16, unit: &str, write_value: impl Fn(&mut W, u16) -> fmt::Result) -> fmt::Result {
+        if let Some(step) = detect_step(values.clone()) {
+            write!(out, "every {} {}s", step.step, unit)?;
+            if !step.is_full(min, max) {
+                out.write_str(", ")?;
+                write_value(out, step.first)?;
+                out.write_str(" through ")?;
+                write_value(out, step.last)?;
+            }
+            return Ok(());
+        }
+
+        let mut runs = Runs::new(values.clone());
+        match (runs.next(), runs.next()) {
+            (Some((first, last)), None) if first == last => out.write_str("only in ")?,
+            (Some(_), None) => (),
+            _ => out.write_str("in ")?,
+        }
+        write_runs(out, values, write_value)
+    }
+}
+
+impl fmt::Display for Describe<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let schedule = self.0;
+        let mut out = Sentence {
+            fmt,
+            is_empty: true,
+            is_start: true,
+        };
+
+        self.write_time(&mut out)?;
+
+        let is_day_m = !schedule.days_of_month().is_any();
+        let is_day_w = !schedule.days_of_week().is_any();
+        match schedule.day_matching() {
+            DayMatching::Or if is_day_m && is_day_w => {
+                self.write_days_of_month(out.clause()?)?;
+                out.write_str(", or ")?;
+                self.write_days_of_week(&mut out, false)?;
+            },
+            _ => {
+                if is_day_m {
+                    self.write_days_of_month(out.clause()?)?;
+                }
+                if is_day_w {
+                    self.write_days_of_week(out.clause()?, true)?;
+                }
+            },
+        }
+
+        let months = schedule.months();
+        if !months.is_all() {
+            Self::write_period(out.clause()?, bit_values(months.bits()), Month::MIN.into(), Month::MAX.into(), "month", write_month)?;
+        }
+
+        if let Some(years) = schedule.years() {
+            let years = years.iter().map(Into::<u16>::into);
+            Self::write_period(out.clause()?, years, crate::Year::MIN, crate::Year::MAX, "year", write_num)?;
+        }
+
+        Ok(())
+    }
+}
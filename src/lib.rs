@@ -2,23 +2,41 @@
 //!
 //!## Syntax
 //!
-//!`<minutes> <hours> <days of month> <months> <days of week>`
+//!`[<seconds>] <minutes> <hours> <days of month> <months> <days of week> [<years>]`
 //!
+//!- `second` is optional integer in range `0..=59`, when omitted `0` is assumed;
 //!- `minute` is integer in range `0..=59`;
 //!- `hour` is integer in range `0..=23`;
-//!- `day of month` is integer in range `1..=31`;
-//!- `month` is integer in range `1..=12` or textual representation like `JAN` or `DEC`;
-//!- `day of week` is integer in range `0..=6` or textual representation like `SUN` or `SAT`;
+//!- `day of month` is integer in range `1..=31`, `L` for last day of month, `L-n` for `n` days before last day, `nW` for weekday nearest to day `n` or `LW` for last weekday of month;
+//!- `month` is integer in range `1..=12` or textual representation like `JAN`, `DEC` or `December`;
+//!- `day of week` is integer in range `0..=7` (both `0` and `7` are Sunday) or textual representation like `SUN`, `SAT` or `Saturday`, `<day>#<n>` for `n`th day of week in month or `<day>L` for last day of week in month;
+//!- `year` is optional integer in range `1970..=2199`, can be specified only together with `second`;
+//!
+//!Each field accepts wildcard `*`, single value `a`, range `a-b` or step `*/n`, `a/n`, `a-b/n` as well as comma separated list of them.
+//!
+//!Alternatively expression can be one of nicknames (case is ignored):
+//!
+//!- `@yearly` or `@annually` - [YEARLY]
+//!- `@monthly` - [MONTHLY]
+//!- `@weekly` - [WEEKLY]
+//!- `@daily` or `@midnight` - [DAILY]
+//!- `@hourly` - [HOURLY]
+//!
+//!Quartz expressions are supported via [CronSchedule::parse_quartz_str], while AWS EventBridge schedule expressions are supported via [aws] module.
+//!
+//!Whole crontab files can be parsed via [crontab] module.
 //!
 //!## Features
 //!
 //!- `std` - Enables use of `std` library types and traits.
 //!- `serde` - Enables serialization/deserialization.
 //!- `time` - Enables schedule calculation using `time03` crate.
+//!- `tz` - Enables time zone aware schedules in [zoned](zoned/index.html) module, using system time zone database. Implies `std` and `time`.
+//!- `tz-bundled` - Enables bundled copy of time zone database. Implies `tz`.
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -26,6 +44,18 @@ extern crate std;
 mod utils;
 mod types;
 pub use types::*;
+mod values;
+pub use values::*;
+mod describe;
+pub use describe::Describe;
+mod canonical;
+pub use canonical::{CanonicalDisplay, Names};
+mod builder;
+pub use builder::{BuildError, CronScheduleBuilder, FieldValue};
+pub mod aws;
+pub mod crontab;
+#[cfg(feature = "tz")]
+pub mod zoned;
 
 #[allow(unused)]
 #[doc(hidden)]
@@ -60,6 +90,39 @@ pub const DAILY: &'static str = "0 0 * * *";
 ///Cron expression to run once a hour.
 pub const HOURLY: &'static str = "0 * * * *";
 
+//Schedules of nicknames, built from schedule matching every minute.
+const HOURLY_SCHEDULE: CronSchedule = CronSchedule {
+    minute: 1,
+    ..CronSchedule::EVERY_MINUTE
+};
+const DAILY_SCHEDULE: CronSchedule = CronSchedule {
+    hour: 1,
+    ..HOURLY_SCHEDULE
+};
+const WEEKLY_SCHEDULE: CronSchedule = CronSchedule {
+    day_w: DaysOfWeek::from_days(Values::from_bits(1 << Day::Sunday as u64)),
+    ..DAILY_SCHEDULE
+};
+const MONTHLY_SCHEDULE: CronSchedule = CronSchedule {
+    day_m: DaysOfMonth::from_days(Values::from_bits(1 << 1)),
+    ..DAILY_SCHEDULE
+};
+const YEARLY_SCHEDULE: CronSchedule = CronSchedule {
+    month: 1 << Month::January as u16,
+    ..MONTHLY_SCHEDULE
+};
+
+//Ordered by preference when displaying schedule as nickname.
+const NICKNAMES: [(&'static str, &'static str, CronSchedule); 7] = [
+    ("@yearly", YEARLY, YEARLY_SCHEDULE),
+    ("@annually", YEARLY, YEARLY_SCHEDULE),
+    ("@monthly", MONTHLY, MONTHLY_SCHEDULE),
+    ("@weekly", WEEKLY, WEEKLY_SCHEDULE),
+    ("@daily", DAILY, DAILY_SCHEDULE),
+    ("@midnight", DAILY, DAILY_SCHEDULE),
+    ("@hourly", HOURLY, HOURLY_SCHEDULE),
+];
+
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(feature = "time")]
@@ -84,8 +147,12 @@ pub enum ParseError {
     InvalidExpr(&'static str, InvalidExpr),
     ///Cron expression is incomplete.
     Incomplete,
-    ///Cron expression includes year field, which is unsupported
+    ///Cron expression includes more fields than supported or unknown nickname (e.g. `@reboot`).
     Unsupported,
+    ///Cron expression never matches any date, e.g. `0 0 30 2 *` or `0 0 31 4,6 *`.
+    ImpossibleDate,
+    ///Day of month and day of week are both `?` or both restricted, which is not supported by Quartz.
+    InvalidDays,
 }
 
 impl fmt::Display for ParseError {
@@ -94,7 +161,9 @@ impl fmt::Display for ParseError {
             Self::InvalidCharAt(ch, pos) => fmt.write_fmt(format_args!("Invalid character '{ch:x}' at position {pos}")),
             Self::InvalidExpr(name, error) => fmt.write_fmt(format_args!("{name}: {:?}", error)),
             Self::Incomplete => fmt.write_str("Incomplete cron expression"),
-            Self::Unsupported => fmt.write_str("Cron expression includes unsupported field (year)"),
+            Self::Unsupported => fmt.write_str("Cron expression includes unsupported field"),
+            Self::ImpossibleDate => fmt.write_str("Cron expression never matches any date"),
+            Self::InvalidDays => fmt.write_str("Either day of month or day of week must be '?' or '*'"),
         }
     }
 }
@@ -102,15 +171,114 @@ impl fmt::Display for ParseError {
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes how day of month and day of week are combined, when both are restricted (i.e. not `*`).
+pub enum DayMatching {
+    ///Day is scheduled when either day of month or day of week matches.
+    ///
+    ///This is standard POSIX/Vixie cron behavior. When one of the fields is `*`, only other field is considered.
+    Or,
+    ///Day is scheduled only when both day of month and day of week match.
+    And,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes flavor of cron expression syntax.
+pub enum Dialect {
+    ///Regular cron syntax, as described in crate documentation.
+    Cron,
+    ///Quartz scheduler syntax.
+    ///
+    ///- Seconds are mandatory, while year is optional;
+    ///- Day of week is numbered from `1` (Sunday) to `7` (Saturday), single `L` stands for Saturday;
+    ///- `?` in day of month or day of week means no specific value, which is the same as `*`;
+    ///- Day of month and day of week cannot be both `?` or both restricted;
+    ///- Year is limited to `2099`;
+    ///- Nicknames are not supported.
+    Quartz,
+    ///AWS EventBridge cron syntax, without `cron(...)` wrapper.
+    ///
+    ///Same as [Dialect::Quartz], except that seconds are not supported, while year is mandatory.
+    ///
+    ///Refer to [aws] module for parsing complete schedule expressions.
+    Aws,
+}
+
+#[derive(Debug, Copy, Clone)]
+///Cron expression parser options.
+pub struct ParseOptions {
+    day_matching: DayMatching,
+    wrap_ranges: bool,
+    dialect: Dialect,
+}
+
+impl ParseOptions {
+    #[inline(always)]
+    ///Creates default options.
+    ///
+    ///- Day matching is [DayMatching::Or];
+    ///- Reversed ranges are rejected;
+    ///- Dialect is [Dialect::Cron].
+    pub const fn new() -> Self {
+        Self {
+            day_matching: DayMatching::Or,
+            wrap_ranges: false,
+            dialect: Dialect::Cron,
+        }
+    }
+
+    #[inline(always)]
+    ///Specifies syntax of expression.
+    pub const fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    #[inline(always)]
+    ///Specifies how day of month and day of week are combined.
+    pub const fn day_matching(mut self, day_matching: DayMatching) -> Self {
+        self.day_matching = day_matching;
+        self
+    }
+
+    #[inline(always)]
+    ///Specifies whether reversed range (e.g. `22-2` or `FRI-MON`) wraps around field's max value.
+    ///
+    ///When disabled, such range results in [InvalidExpr::InvalidRangeRev].
+    pub const fn wrap_ranges(mut self, wrap_ranges: bool) -> Self {
+        self.wrap_ranges = wrap_ranges;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 ///Cron schedule.
 ///
+///## Day matching
+///
+///When both day of month and day of week are restricted, day is scheduled if either of them matches.
+///For example `0 0 1 * MON` runs on 1st day of month and on every Monday.
+///Use [ParseOptions::day_matching] to require both of them instead.
+///
+///Note that mode is not part of textual representation.
+///When serialized, [DayMatching::And] is written as `@and ` prefix, e.g. `@and 0 0 1 * MON`.
+///
 ///## Size
 ///
-///184 bytes.
+///64 bytes.
+///
+///Each field except year is stored as bitmask of allowed values, which is about 10 times smaller than list of values.
+///Bitmasks of 5 basic fields alone would fit in 24 bytes, but the rest cannot be dropped without losing expressions:
 ///
-///This is relatively big struct, which might be better suited to be allocated on heap.
-///So if you expect to move it a lot, prefer heap.
-///Alternatively you could store cron expression as `String` and parse it each time.
+///- Years take 16 bytes, as evenly spaced years or up to 64 years with common step, refer to [Years];
+///- Day of month keeps `L-n` offsets and `nW` days in separate 4 bytes masks each;
+///- Day of week keeps `n`th day of week for each week of the month and `L` days.
 ///
 ///## Usage
 ///
@@ -118,29 +286,101 @@ impl std::error::Error for ParseError {}
 ///use cronchik::CronSchedule;
 ///
 ///let schedule = CronSchedule::parse_str("5 * * * *").unwrap();
-///assert_eq!(core::mem::size_of::<CronSchedule>(), 184);
+///assert_eq!(core::mem::size_of::<CronSchedule>(), 64);
 ///let display = format!("{}", schedule);
 ///assert_eq!(display, "5 * * * *");
 ///```
 #[derive(Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "serde_on", derive(Serialize, Deserialize))]
 pub struct CronSchedule {
-    minute: statiki::Array<Minute, {(Minute::MAX - Minute::MIN) as usize + 1}>,
-    hour: statiki::Array<Hour, {(Hour::MAX - Hour::MIN) as usize + 1}>,
-    day_m: statiki::Array<DayOfMonth, {(DayOfMonth::MAX - DayOfMonth::MIN) as usize + 1}>,
-    month: statiki::Array<Month, {(Month::MAX - Month::MIN) as usize + 1}>,
-    day_w: statiki::Array<Day, {(Day::MAX - Day::MIN) as usize + 1}>,
+    //Bitmasks, where bit `n` stands for value `n`.
+    second: u64,
+    minute: u64,
+    //Empty set means any year
+    year: Years,
+    hour: u32,
+    day_m: DaysOfMonth,
+    month: u16,
+    day_w: DaysOfWeek,
+    day_matching: DayMatching,
 }
 
 impl CronSchedule {
+    //Schedule, matching start of every minute.
+    pub(crate) const EVERY_MINUTE: Self = Self {
+        second: 1,
+        minute: Values::<Minute>::ALL,
+        year: Years::new(),
+        hour: Values::<Hour>::ALL as u32,
+        day_m: DaysOfMonth::from_days(Values::from_bits(Values::<DayOfMonth>::ALL)),
+        month: Values::<Month>::ALL as u16,
+        day_w: DaysOfWeek::from_days(Values::from_bits(Values::<Day>::ALL)),
+        day_matching: DayMatching::Or,
+    };
+
     ///Parses cron expression from string.
+    ///
+    ///Seconds field is optional and is recognized by number of fields:
+    ///
+    ///- 5 fields - `<minutes> <hours> <days of month> <months> <days of week>`, seconds are set to `0`;
+    ///- 6 fields - `<seconds> <minutes> <hours> <days of month> <months> <days of week>`;
+    ///- 7 fields - `<seconds> <minutes> <hours> <days of month> <months> <days of week> <years>`.
+    ///
+    ///Year field with value `*` is the same as omitting it.
+    ///
+    ///Nicknames like `@daily` are accepted in place of expression.
+    #[inline]
     pub fn parse_str(text: &str) -> Result<Self, ParseError> {
-        let mut text = text.trim().split_whitespace();
+        Self::parse_str_with(text, ParseOptions::new())
+    }
+
+    ///Parses Quartz cron expression from string.
+    ///
+    ///`<seconds> <minutes> <hours> <days of month> <months> <days of week> [<years>]`
+    ///
+    ///Refer to [Dialect::Quartz] for differences from regular syntax.
+    ///
+    ///Quartz allows `?` in only one of day of month and day of week, and doesn't support restricting both of them,
+    ///hence such expressions are rejected with [ParseError::InvalidDays].
+    ///
+    ///```
+    ///use cronchik::CronSchedule;
+    ///
+    ///let schedule = CronSchedule::parse_quartz_str("0 0/15 8-17 ? * MON-FRI").unwrap();
+    ///assert_eq!(schedule, CronSchedule::parse_str("0 */15 8-17 * * 1-5").unwrap());
+    ///assert_eq!(format!("{}", schedule.display_quartz()), "0 0,15,30,45 8-17 ? * MON-FRI");
+    ///```
+    #[inline]
+    pub fn parse_quartz_str(text: &str) -> Result<Self, ParseError> {
+        Self::parse_str_with(text, ParseOptions::new().dialect(Dialect::Quartz))
+    }
+
+    ///Parses cron expression from string, using provided `options`.
+    ///
+    ///Refer to [parse_str](#method.parse_str) or [parse_quartz_str](#method.parse_quartz_str) for syntax, depending on dialect.
+    pub fn parse_str_with(text: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        let text = text.trim();
+        if options.dialect == Dialect::Cron && text.starts_with('@') {
+            return match NICKNAMES.iter().find(|(nickname, _, _)| nickname.eq_ignore_ascii_case(text)) {
+                Some((_, expr, _)) => Self::parse_str_with(expr, options),
+                None => Err(ParseError::Unsupported),
+            };
+        }
+
+        let fields_num = text.split_whitespace().count();
+        let mut text = text.split_whitespace();
+        //Quartz has seconds, hence days are 4th and 6th fields.
+        let quartz_no_days = match options.dialect {
+            Dialect::Quartz => {
+                let mut fields = text.clone();
+                fields.nth(3) == Some("?") && fields.nth(1) == Some("?")
+            },
+            Dialect::Cron | Dialect::Aws => false,
+        };
 
         macro_rules! parse_next {
             ($ty:ty) => {
                 match text.next() {
-                    Some(text) => match <$ty>::from_expr(text) {
+                    Some(text) => match <$ty>::from_expr_with(text, options) {
                         Ok(result) => result,
                         Err(error) => return Err(ParseError::InvalidExpr(<$ty>::NAME, error)),
                     },
@@ -149,160 +389,602 @@ impl CronSchedule {
             }
         }
 
-        //let second = parse_next!(Second);
+        let has_second = match options.dialect {
+            Dialect::Cron => fields_num > 5,
+            Dialect::Quartz => true,
+            Dialect::Aws => false,
+        };
+        let second = match has_second {
+            true => parse_next!(Second),
+            false => {
+                let mut second = Values::new();
+                second.insert(0);
+                second
+            }
+        };
         let minute = parse_next!(Minute);
         let hour = parse_next!(Hour);
         let day_m = parse_next!(DayOfMonth);
         let month = parse_next!(Month);
         let day_w = parse_next!(Day);
+        let year = match text.next() {
+            Some("*") => Years::new(),
+            Some(text) => match Year::from_expr_with(text, options) {
+                //Every year is the same as unrestricted year, which is stored as empty set.
+                Ok(result) if result.is_all() => Years::new(),
+                Ok(result) => result,
+                Err(error) => return Err(ParseError::InvalidExpr(Year::NAME, error)),
+            },
+            None if options.dialect == Dialect::Aws => return Err(ParseError::Incomplete),
+            None => Years::new(),
+        };
 
-        if let Some(_) = text.next() {
+        if text.next().is_some() {
             return Err(ParseError::Unsupported);
         }
 
-        Ok(Self {
-            minute,
-            hour,
+        if quartz_no_days || (options.dialect == Dialect::Quartz && !day_m.is_any() && !day_w.is_any()) {
+            return Err(ParseError::InvalidDays);
+        }
+
+        let schedule = Self {
+            second: second.bits(),
+            minute: minute.bits(),
+            year,
+            hour: hour.bits() as u32,
             day_m,
-            month,
+            month: month.bits() as u16,
             day_w,
-        })
+            day_matching: options.day_matching,
+        };
+        match schedule.has_date() {
+            true => Ok(schedule),
+            false => Err(ParseError::ImpossibleDate),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns builder to create schedule out of field values, without formatting expression.
+    pub const fn builder() -> CronScheduleBuilder {
+        CronScheduleBuilder::new()
+    }
+
+    ///Returns whether there is at least one date that matches schedule.
+    fn has_date(&self) -> bool {
+        let has_day = |year: i32, month: Month| {
+            let month = month.into();
+            self.next_day(1, utils::days_in_month(year, month), utils::weekday(year, month, 1)).is_some()
+        };
+
+        match self.years() {
+            Some(years) => years.iter().any(|year| {
+                let year: u16 = year.into();
+                self.months().iter().any(|month| has_day(year.into(), month))
+            }),
+            //Every combination of month's length and weekday of its first day occurs within 28 years.
+            None => self.months().iter().any(|month| (2000..2028).any(|year| has_day(year, month))),
+        }
     }
 
     #[inline(always)]
-    ///Returns ordered list of scheduled days in month to run at.
-    pub fn days_of_month(&self) -> &[DayOfMonth] {
+    ///Returns scheduled days in month to run at.
+    pub fn days_of_month(&self) -> &DaysOfMonth {
         &self.day_m
     }
 
     #[inline(always)]
-    ///Returns ordered list of scheduled minutes to run at.
-    pub fn minutes(&self) -> &[Minute] {
-        &self.minute
+    ///Returns set of scheduled seconds to run at.
+    pub fn seconds(&self) -> Values<Second> {
+        Values::from_bits(self.second)
     }
 
     #[inline(always)]
-    ///Returns ordered list of scheduled hours to run at.
-    pub fn hours(&self) -> &[Hour] {
-        &self.hour
+    ///Returns set of scheduled minutes to run at.
+    pub fn minutes(&self) -> Values<Minute> {
+        Values::from_bits(self.minute)
     }
 
     #[inline(always)]
-    ///Returns ordered list of scheduled days in week to run at.
-    pub fn days_of_week(&self) -> &[Day] {
+    ///Returns set of scheduled hours to run at.
+    pub fn hours(&self) -> Values<Hour> {
+        Values::from_bits(self.hour as u64)
+    }
+
+    #[inline(always)]
+    ///Returns scheduled days in week to run at.
+    pub fn days_of_week(&self) -> &DaysOfWeek {
         &self.day_w
     }
 
     #[inline(always)]
-    ///Returns ordered list of scheduled months to run at.
-    pub fn months(&self) -> &[Month] {
-        &self.month
+    ///Returns set of scheduled months to run at.
+    pub fn months(&self) -> Values<Month> {
+        Values::from_bits(self.month as u64)
+    }
+
+    #[inline(always)]
+    ///Returns set of scheduled years to run at.
+    ///
+    ///`None` means schedule runs every year.
+    pub fn years(&self) -> Option<Years> {
+        match self.year.is_empty() {
+            true => None,
+            false => Some(self.year),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns how day of month and day of week are combined.
+    pub fn day_matching(&self) -> DayMatching {
+        self.day_matching
+    }
+
+    #[inline(always)]
+    ///Returns wrapper to display schedule as Quartz cron expression.
+    pub fn display_quartz(&self) -> QuartzDisplay<'_> {
+        QuartzDisplay(self)
+    }
+
+    #[inline(always)]
+    ///Returns wrapper to display schedule as the shortest cron expression, with months and days of week written according to `names`.
+    pub fn display_canonical(&self, names: Names) -> CanonicalDisplay<'_> {
+        CanonicalDisplay {
+            schedule: self,
+            names,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns wrapper to display schedule as English text, like `At 5 minutes past the hour, Monday through Friday`.
+    pub fn describe(&self) -> Describe<'_> {
+        Describe(self)
+    }
+
+    ///Returns first day of the month, starting from `day`, that matches both day of month and day of week.
+    fn next_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        match self.day_matching {
+            DayMatching::Or if !self.day_m.is_any() && !self.day_w.is_any() => {
+                match (self.day_m.next_day(day, days_in_month, first_weekday), self.day_w.next_day(day, days_in_month, first_weekday)) {
+                    (Some(day_m), Some(day_w)) => Some(core::cmp::min(day_m, day_w)),
+                    (day_m, day_w) => day_m.or(day_w),
+                }
+            },
+            _ => {
+                let mut day = day;
+                loop {
+                    let day_m = self.day_m.next_day(day, days_in_month, first_weekday)?;
+                    day = self.day_w.next_day(day_m, days_in_month, first_weekday)?;
+                    if day == day_m {
+                        break Some(day);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns last day of the month, up to `day`, that matches both day of month and day of week.
+    fn prev_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        match self.day_matching {
+            DayMatching::Or if !self.day_m.is_any() && !self.day_w.is_any() => {
+                match (self.day_m.prev_day(day, days_in_month, first_weekday), self.day_w.prev_day(day, days_in_month, first_weekday)) {
+                    (Some(day_m), Some(day_w)) => Some(core::cmp::max(day_m, day_w)),
+                    (day_m, day_w) => day_m.or(day_w),
+                }
+            },
+            _ => {
+                let mut day = day;
+                loop {
+                    let day_m = self.day_m.prev_day(day, days_in_month, first_weekday)?;
+                    day = self.day_w.prev_day(day_m, days_in_month, first_weekday)?;
+                    if day == day_m {
+                        break Some(day);
+                    }
+                }
+            }
+        }
     }
 
     #[cfg(feature = "time")]
+    #[inline]
     ///Returns next point if time, after `time`, accordingly to the schedule.
     ///
     ///Available with `time` feature
+    ///
+    ///## Panics
+    ///
+    ///If schedule has no more points in time after `time`, which is possible only when year field is specified.
+    ///Use [try_next_time_from](#method.try_next_time_from) to handle it.
     pub fn next_time_from(&self, time: time::OffsetDateTime) -> time::OffsetDateTime {
-        let offset = time.offset();
-        let mut next = time + time::Duration::minutes(1);
-        next = match next.replace_second(0) {
-            Ok(next) => next,
-            Err(_) => unreach!(),
-        };
-        next = match next.replace_nanosecond(0) {
-            Ok(next) => next,
-            Err(_) => unreach!(),
-        };
+        self.try_next_time_from(time).expect("Schedule has no more points in time after last allowed year")
+    }
 
-        let result = loop {
-            debug_assert_ne!(next.year() - time.year(), 5, "Unable to find  schedule within 4 years");
+    #[cfg(feature = "time")]
+    ///Returns first time of the day, starting from `hour:minute:second`, that matches schedule.
+    fn next_time_of_day(&self, hour: u8, minute: u8, second: u8) -> Option<(u8, u8, u8)> {
+        let hours = self.hours();
+        let minutes = self.minutes();
+        let seconds = self.seconds();
 
-            let month = next.month() as u8;
-            let day = next.day();
+        //When current value has no more matches in lower field, it carries to the next one.
+        let mut next_hour = hour;
+        if hours.next_from(hour) == Some(hour) {
+            let mut next_minute = minute;
+            if minutes.next_from(minute) == Some(minute) {
+                if let Some(second) = seconds.next_from(second) {
+                    return Some((hour, minute, second));
+                }
+                next_minute += 1;
+            }
 
-            if let Err(idx) = self.month.binary_search(&Month::from_num_asserted(month)) {
-                let date = match self.month.get(idx) {
-                    Some(month) => time::Date::from_calendar_date(next.year(), (*month).into(), 1).expect("Get next month date"),
-                    None => time::Date::from_calendar_date(next.year() + 1, time::Month::January, 1).expect("Get next year date"),
-                };
+            if let Some(minute) = minutes.next_from(next_minute) {
+                return Some((hour, minute, seconds.next_from(0)?));
+            }
+            next_hour += 1;
+        }
 
-                let date_time = time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT);
-                next = date_time.assume_offset(offset);
+        Some((hours.next_from(next_hour)?, minutes.next_from(0)?, seconds.next_from(0)?))
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns first date, starting from `year-month-day`, that matches schedule.
+    ///
+    ///`day` is allowed to exceed number of days in month, in which case search starts from next month.
+    fn next_date(&self, mut year: i32, mut month: u8, mut day: u8) -> Option<(i32, u8, u8)> {
+        //Weekdays repeat every 400 years, so there is no point to search further.
+        let max_year = core::cmp::min(year.saturating_add(400), time::Date::MAX.year());
+        let months = self.months();
 
-                continue;
+        while year <= max_year {
+            if !self.year.is_empty() {
+                //time's year cannot exceed 9999 so it is always fits u16 when positive
+                let next_year: u16 = self.year.next_from(core::cmp::max(year, Year::MIN.into()) as u16)?.into();
+                if i32::from(next_year) != year {
+                    year = next_year.into();
+                    month = Month::MIN;
+                    day = 1;
+                    continue;
+                }
             }
 
-            if let Err(idx) = self.day_m.binary_search(&DayOfMonth::from_num_asserted(day)) {
-                //If not today, check next available day in schedule, if any.
-                let date = match self.day_m.get(idx).and_then(|day| time::Date::from_calendar_date(next.year(), Month::from_num_asserted(month).into(), (*day).into()).ok()) {
-                    Some(date) => date,
-                    //If next allowed day doesn't fit the current month, then just switch to next month, unless it is last month
-                    None if month < Month::MAX => time::Date::from_calendar_date(next.year(), Month::from_num_asserted(month + 1).into(), 1).expect("Get next month date"),
-                    //If it is last month, then switch to next year.
-                    None => time::Date::from_calendar_date(next.year() + 1, time::Month::January, 1).expect("Get next year date"),
-                };
+            while let Some(next_month) = months.next_from(month) {
+                if next_month != month {
+                    month = next_month;
+                    day = 1;
+                }
 
-                let date_time = time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT);
-                next = date_time.assume_offset(offset);
+                let days_in_month = utils::days_in_month(year, month);
+                if day <= days_in_month {
+                    if let Some(day) = self.next_day(day, days_in_month, utils::weekday(year, month, 1)) {
+                        return Some((year, month, day));
+                    }
+                }
 
-                continue;
+                month += 1;
+                day = 1;
             }
 
-            let weekday = next.weekday();
-            let weekday_s = weekday.number_days_from_sunday();
-            if let Err(idx) = self.day_w.binary_search(&Day::from_num_asserted(weekday_s)) {
-                let date = match self.day_w.get(idx) {
-                    Some(day_w) => match time::Date::from_calendar_date(next.year(), Month::from_num_asserted(month).into(), day + *day_w as u8 - weekday_s) {
-                        //Day is on current week.
-                        Ok(date) => date,
-                        //Day is in next month so iterate onto next month (note weekday enum is in range 0..6)
-                        Err(_) if month < Month::MAX => time::Date::from_calendar_date(next.year(), Month::from_num_asserted(month + 1).into(), *day_w as u8 - weekday_s).expect("Get next month date"),
-                        //Day is in next year so iterate onto next month (note weekday enum is in range 0..6)
-                        Err(_) => time::Date::from_calendar_date(next.year() + 1, time::Month::January, *day_w as u8 - weekday_s).expect("Get next year date"),
-                    },
-                    //This week doesn't work, iterate onto next week by number of days until Sunday
-                    None => next.date() + time::Duration::days(time::Weekday::Sunday as i64 - weekday as i64),
-                };
+            year += 1;
+            month = Month::MIN;
+            day = 1;
+        }
+
+        None
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns last time of the day, up to `hour:minute:second`, that matches schedule.
+    fn prev_time_of_day(&self, hour: u8, minute: u8, second: u8) -> Option<(u8, u8, u8)> {
+        let hours = self.hours();
+        let minutes = self.minutes();
+        let seconds = self.seconds();
 
-                let date_time = time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT);
-                next = date_time.assume_offset(offset);
+        //When current value has no more matches in lower field, it borrows from the previous one.
+        let mut prev_hour = Some(hour);
+        if hours.prev_from(hour) == Some(hour) {
+            let mut prev_minute = Some(minute);
+            if minutes.prev_from(minute) == Some(minute) {
+                if let Some(second) = seconds.prev_from(second) {
+                    return Some((hour, minute, second));
+                }
+                prev_minute = minute.checked_sub(1);
+            }
 
-                continue;
+            if let Some(minute) = prev_minute.and_then(|minute| minutes.prev_from(minute)) {
+                return Some((hour, minute, seconds.prev_from(Second::MAX)?));
             }
+            prev_hour = hour.checked_sub(1);
+        }
 
-            let hour = next.hour();
-            if let Err(idx) = self.hour.binary_search(&Hour::from_num_asserted(hour)) {
-                let (date, time) = match self.hour.get(idx) {
-                    Some(hour) => (next.date(), time::Time::from_hms((*hour).into(), 0, 0).expect("Get next hour")),
-                    //Try next day
-                    None => (next.date() + time::Duration::days(1), time::Time::MIDNIGHT),
-                };
+        Some((hours.prev_from(prev_hour?)?, minutes.prev_from(Minute::MAX)?, seconds.prev_from(Second::MAX)?))
+    }
 
-                next = time::PrimitiveDateTime::new(date, time).assume_offset(offset);
-                continue;
+    #[cfg(feature = "time")]
+    ///Returns last date, up to `year-month-day`, that matches schedule.
+    ///
+    ///`day` is allowed to exceed number of days in month, in which case search starts from last day of month.
+    ///While `0` starts search from previous month.
+    fn prev_date(&self, mut year: i32, mut month: u8, mut day: u8) -> Option<(i32, u8, u8)> {
+        //Weekdays repeat every 400 years, so there is no point to search further.
+        let min_year = core::cmp::max(year.saturating_sub(400), time::Date::MIN.year());
+        let months = self.months();
+
+        while year >= min_year {
+            if !self.year.is_empty() {
+                //Years before the first allowed one cannot match.
+                if year < Year::MIN.into() {
+                    return None;
+                }
+                let prev_year: u16 = self.year.prev_from(core::cmp::min(year, Year::MAX.into()) as u16)?.into();
+                if i32::from(prev_year) != year {
+                    year = prev_year.into();
+                    month = Month::MAX;
+                    day = DayOfMonth::MAX;
+                    continue;
+                }
             }
 
-            let minute = next.minute();
-            if let Err(idx) = self.minute.binary_search(&Minute::from_num_asserted(minute)) {
-                match self.minute.get(idx) {
-                    Some(minute) => {
-                        let time = time::Time::from_hms(hour, (*minute).into(), 0).expect("Get next minute");
-                        next = time::PrimitiveDateTime::new(next.date(), time).assume_offset(offset);
-                    },
-                    //Next hour
-                    None => {
-                        let time = time::Time::from_hms(hour, 0, 0).expect("Get current hour");
-                        next = time::PrimitiveDateTime::new(next.date(), time).assume_offset(offset) + time::Duration::hours(1);
+            while let Some(prev_month) = months.prev_from(month) {
+                if prev_month != month {
+                    month = prev_month;
+                    day = DayOfMonth::MAX;
+                }
+
+                let days_in_month = utils::days_in_month(year, month);
+                let last_day = core::cmp::min(day, days_in_month);
+                if last_day > 0 {
+                    if let Some(day) = self.prev_day(last_day, days_in_month, utils::weekday(year, month, 1)) {
+                        return Some((year, month, day));
                     }
                 }
-                continue;
+
+                month -= 1;
+                day = DayOfMonth::MAX;
             }
 
-            break next;
+            year -= 1;
+            month = Month::MAX;
+            day = DayOfMonth::MAX;
+        }
+
+        None
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns next point if time, after `time`, accordingly to the schedule.
+    ///
+    ///Returns `None` if last year allowed by schedule has passed.
+    ///
+    ///Available with `time` feature
+    pub fn try_next_time_from(&self, time: time::OffsetDateTime) -> Option<time::OffsetDateTime> {
+        let next = time.checked_add(time::Duration::seconds(1))?;
+        let (year, month, day) = next.to_calendar_date();
+        let (hour, minute, second) = next.to_hms();
+        let start = (year, month as u8, day);
+
+        let mut date = self.next_date(start.0, start.1, start.2)?;
+        if date == start {
+            if let Some((hour, minute, second)) = self.next_time_of_day(hour, minute, second) {
+                //Same day, hence only time needs to be replaced.
+                return time::Time::from_hms(hour, minute, second).ok().map(|time| next.replace_time(time));
+            }
+            date = self.next_date(start.0, start.1, start.2 + 1)?;
+        }
+
+        let (year, month, day) = date;
+        let (hour, minute, second) = self.next_time_of_day(0, 0, 0)?;
+        let date = time::Date::from_calendar_date(year, Month::from_num_asserted(month).into(), day).ok()?;
+        let date_time = date.with_hms(hour, minute, second).ok()?;
+        Some(date_time.assume_offset(time.offset()))
+    }
+
+    #[cfg(feature = "time")]
+    #[inline]
+    ///Returns last point in time, before `time`, accordingly to the schedule.
+    ///
+    ///Available with `time` feature
+    ///
+    ///## Panics
+    ///
+    ///If schedule has no points in time before `time`, which is possible only when year field is specified.
+    ///Use [try_prev_time_from](#method.try_prev_time_from) to handle it.
+    pub fn prev_time_from(&self, time: time::OffsetDateTime) -> time::OffsetDateTime {
+        self.try_prev_time_from(time).expect("Schedule has no points in time before first allowed year")
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns last point in time, before `time`, accordingly to the schedule.
+    ///
+    ///Returns `None` if first year allowed by schedule is yet to come.
+    ///
+    ///Available with `time` feature
+    pub fn try_prev_time_from(&self, time: time::OffsetDateTime) -> Option<time::OffsetDateTime> {
+        //Fraction of second is dropped, hence whole second is already before `time`.
+        let prev = match time.nanosecond() {
+            0 => time.checked_sub(time::Duration::seconds(1))?,
+            _ => time.replace_nanosecond(0).ok()?,
+        };
+        let (year, month, day) = prev.to_calendar_date();
+        let (hour, minute, second) = prev.to_hms();
+        let start = (year, month as u8, day);
+
+        let mut date = self.prev_date(start.0, start.1, start.2)?;
+        if date == start {
+            if let Some((hour, minute, second)) = self.prev_time_of_day(hour, minute, second) {
+                //Same day, hence only time needs to be replaced.
+                return time::Time::from_hms(hour, minute, second).ok().map(|time| prev.replace_time(time));
+            }
+            date = self.prev_date(start.0, start.1, start.2 - 1)?;
+        }
+
+        let (year, month, day) = date;
+        let (hour, minute, second) = self.prev_time_of_day(Hour::MAX, Minute::MAX, Second::MAX)?;
+        let date = time::Date::from_calendar_date(year, Month::from_num_asserted(month).into(), day).ok()?;
+        let date_time = date.with_hms(hour, minute, second).ok()?;
+        Some(date_time.assume_offset(time.offset()))
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns whether `time` is point in time of the schedule.
+    ///
+    ///Fraction of second is ignored, while seconds must match (i.e. be `0` for expressions without seconds).
+    ///
+    ///Available with `time` feature
+    pub fn matches(&self, time: time::OffsetDateTime) -> bool {
+        let (year, month, day) = time.to_calendar_date();
+        let (hour, minute, second) = time.to_hms();
+
+        let is_time = self.seconds().next_from(second) == Some(second)
+            && self.minutes().next_from(minute) == Some(minute)
+            && self.hours().next_from(hour) == Some(hour);
+
+        is_time && self.is_date(year, month as u8, day)
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns whether `year-month-day` matches schedule.
+    fn is_date(&self, year: i32, month: u8, day: u8) -> bool {
+        if !self.year.is_empty() {
+            let is_year = year >= Year::MIN.into() && year <= Year::MAX.into() && self.year.contains(Year::from_num_asserted(year as u16));
+            if !is_year {
+                return false;
+            }
+        }
+
+        self.months().next_from(month) == Some(month)
+            && self.next_day(day, utils::days_in_month(year, month), utils::weekday(year, month, 1)) == Some(day)
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns number of times of the day, starting from `hour:minute:second`, that match schedule.
+    fn count_time_of_day(&self, hour: u8, minute: u8, second: u8) -> u64 {
+        let hours = self.hours();
+        let minutes = self.minutes();
+        let seconds = self.seconds();
+        //Number of values in set, that are greater or equal to `num`.
+        let count_from = |bits: u64, num: u8| match num < 64 {
+            true => u64::from((bits & (u64::MAX << num)).count_ones()),
+            false => 0,
         };
 
-        result
+        let mut count = count_from(hours.bits(), hour + 1) * minutes.len() as u64 * seconds.len() as u64;
+        if hours.next_from(hour) == Some(hour) {
+            count += count_from(minutes.bits(), minute + 1) * seconds.len() as u64;
+            if minutes.next_from(minute) == Some(minute) {
+                count += count_from(seconds.bits(), second);
+            }
+        }
+        count
+    }
+
+    #[cfg(feature = "time")]
+    #[inline(always)]
+    ///Returns iterator over points in time after `time`, accordingly to the schedule.
+    ///
+    ///Points are calculated lazily in `time`'s offset, and iterator ends when schedule has no more of them.
+    ///
+    ///Available with `time` feature
+    pub fn iter_from(&self, time: time::OffsetDateTime) -> ScheduleIter<'_> {
+        ScheduleIter {
+            schedule: self,
+            state: IterState::Start(time),
+            rev: false,
+            end: None,
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[inline]
+    ///Returns iterator over points in time within `[start, end)`, accordingly to the schedule.
+    ///
+    ///Points are calculated lazily in `start`'s offset.
+    ///
+    ///Available with `time` feature
+    pub fn occurrences_between(&self, start: time::OffsetDateTime, end: time::OffsetDateTime) -> ScheduleIter<'_> {
+        //Points are whole seconds, so nanosecond before `start` gives the same points as `start` itself.
+        let state = match start.checked_sub(time::Duration::nanoseconds(1)) {
+            Some(start) => IterState::Start(start),
+            None => IterState::Done,
+        };
+        ScheduleIter {
+            schedule: self,
+            state,
+            rev: false,
+            end: Some(end),
+        }
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns number of points in time within `[start, end)`, accordingly to the schedule.
+    ///
+    ///Unlike counting [occurrences_between](#method.occurrences_between), only matching days are visited.
+    ///
+    ///Available with `time` feature
+    pub fn count_between(&self, start: time::OffsetDateTime, end: time::OffsetDateTime) -> u64 {
+        //Round to whole seconds, so that `[start, end)` contains the same points.
+        let round_up = |time: time::OffsetDateTime| match time.nanosecond() {
+            0 => Some(time),
+            _ => time.replace_nanosecond(0).ok()?.checked_add(time::Duration::seconds(1)),
+        };
+        let (start, end) = match (round_up(start), round_up(end)) {
+            (Some(start), Some(end)) if start < end => (start, end.to_offset(start.offset())),
+            _ => return 0,
+        };
+
+        let (year, month, day) = start.to_calendar_date();
+        let start_date = (year, month as u8, day);
+        let (year, month, day) = end.to_calendar_date();
+        let end_date = (year, month as u8, day);
+        let (hour, minute, second) = start.to_hms();
+        let start_time = self.count_time_of_day(hour, minute, second);
+        let (hour, minute, second) = end.to_hms();
+        let end_time = self.count_time_of_day(hour, minute, second);
+
+        let is_start_date = self.is_date(start_date.0, start_date.1, start_date.2);
+        if start_date == end_date {
+            return match is_start_date {
+                true => start_time - end_time,
+                false => 0,
+            };
+        }
+
+        let day_count = self.count_time_of_day(0, 0, 0);
+        let mut count = match is_start_date {
+            true => start_time,
+            false => 0,
+        };
+        let (mut year, mut month, mut day) = start_date;
+        while let Some(date) = self.next_date(year, month, day + 1) {
+            if date >= end_date {
+                if date == end_date {
+                    count += day_count - end_time;
+                }
+                break;
+            }
+
+            count += day_count;
+            year = date.0;
+            month = date.1;
+            day = date.2;
+        }
+
+        count
+    }
+
+    #[cfg(feature = "time")]
+    #[inline(always)]
+    ///Returns iterator over points in time before `time` in descending order, accordingly to the schedule.
+    ///
+    ///Points are calculated lazily in `time`'s offset, and iterator ends when schedule has no more of them.
+    ///
+    ///Available with `time` feature
+    pub fn rev_iter_from(&self, time: time::OffsetDateTime) -> ScheduleIter<'_> {
+        ScheduleIter {
+            schedule: self,
+            state: IterState::Start(time),
+            rev: true,
+            end: None,
+        }
     }
 
     #[cfg(feature = "time")]
@@ -313,81 +995,204 @@ impl CronSchedule {
     pub fn next_time_from_now(&self) -> time::OffsetDateTime {
         self.next_time_from(time::OffsetDateTime::now_utc())
     }
-}
 
-impl core::fmt::Debug for CronSchedule {
-    #[inline(always)]
-    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Display::fmt(self, fmt)
+    ///Returns nickname, which schedule matches exactly.
+    ///
+    ///Day matching is ignored, as nicknames restrict at most one of day fields.
+    pub(crate) fn nickname(&self) -> Option<&'static str> {
+        let matches = |schedule: &Self| *self == Self { day_matching: self.day_matching, ..schedule.clone() };
+        NICKNAMES.iter().find(|(_, _, schedule)| matches(schedule)).map(|(nickname, _, _)| *nickname)
     }
-}
 
-impl core::fmt::Display for CronSchedule {
-    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    ///Writes expression fields accordingly to `dialect`.
+    pub(crate) fn write_expr(&self, fmt: &mut core::fmt::Formatter<'_>, dialect: Dialect) -> core::fmt::Result {
         macro_rules! write_expr {
-            ($name:ident) => {
-                if self.$name.len() == self.$name.capacity() {
+            ($field:expr) => {
+                if $field.is_all() {
                     fmt.write_str("*")?;
                 } else {
-                    let elems = self.$name.as_slice();
-                    debug_assert_ne!(elems.len(), 0);
-
-                    let mut is_first = true;
-                    let mut start = elems[0];
-                    let mut end = start;
-                    let mut prev: u8 = start.into();
-
-                    let mut elems = elems.iter().skip(1);
-                    while let Some(elem) = elems.next() {
-                        let elem_repr: u8 = (*elem).into();
-
-                        if (prev + 1) == elem_repr {
-                            end = *elem;
-                        } else {
-                            if !is_first {
-                                fmt.write_str(",")?;
-                            }
-
-                            is_first = false;
-                            if start == end {
-                                fmt.write_fmt(format_args!("{}", start))?;
-                            } else {
-                                fmt.write_fmt(format_args!("{}-{}", start, end))?;
-                            }
-
-                            start = *elem;
-                            end = *elem;
-                        }
-
-                        prev = end.into();
-                    }
-
-                    if !is_first {
-                        fmt.write_str(",")?;
-                    }
-
-                    if start == end {
-                        fmt.write_fmt(format_args!("{}", start))?;
-                    } else {
-                        fmt.write_fmt(format_args!("{}-{}", start, end))?;
-                    }
+                    utils::write_list(fmt, $field)?;
                 }
             }
         }
 
-        write_expr!(minute);
+        let has_second = match dialect {
+            //Seconds are omitted when they match default of 5 fields expression, unless year is present.
+            Dialect::Cron => !self.year.is_empty() || self.second != 1,
+            Dialect::Quartz => true,
+            Dialect::Aws => false,
+        };
+        if has_second {
+            write_expr!(self.seconds());
+            fmt.write_str(" ")?;
+        }
+        write_expr!(self.minutes());
         fmt.write_str(" ")?;
-        write_expr!(hour);
+        write_expr!(self.hours());
         fmt.write_str(" ")?;
-        write_expr!(day_m);
+        //Quartz expects `?` in place of unrestricted day field, preferably in day of week.
+        match dialect != Dialect::Cron && self.day_m.is_any() && !self.day_w.is_any() {
+            true => fmt.write_str("?")?,
+            false => fmt::Display::fmt(&self.day_m, fmt)?,
+        }
         fmt.write_str(" ")?;
-        write_expr!(month);
+        write_expr!(self.months());
         fmt.write_str(" ")?;
-        write_expr!(day_w);
+        match dialect != Dialect::Cron && self.day_w.is_any() {
+            true => fmt.write_str("?")?,
+            false => fmt::Display::fmt(&self.day_w, fmt)?,
+        }
+        match self.years() {
+            Some(year) => {
+                fmt.write_str(" ")?;
+                write_expr!(year);
+            },
+            None if dialect == Dialect::Aws => fmt.write_str(" *")?,
+            None => (),
+        }
         Ok(())
     }
 }
 
+impl core::fmt::Debug for CronSchedule {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, fmt)
+    }
+}
+
+impl core::fmt::Display for CronSchedule {
+    ///Writes cron expression.
+    ///
+    ///With alternate flag (`{:#}`) nickname is written instead when schedule matches it exactly, regardless of day matching.
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            if let Some(nickname) = self.nickname() {
+                return fmt.write_str(nickname);
+            }
+        }
+
+        self.write_expr(fmt, Dialect::Cron)
+    }
+}
+
+///Wrapper to display [CronSchedule] as Quartz cron expression.
+///
+///Seconds are always written, while days of week are written using textual representation.
+///
+///Quartz supports neither restricting both day of month and day of week, nor years after `2099`.
+///Such schedule is still written as is (e.g. `0 0 0 1 * MON`), but the result is rejected by [CronSchedule::parse_quartz_str].
+pub struct QuartzDisplay<'a>(&'a CronSchedule);
+
+impl fmt::Display for QuartzDisplay<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_expr(fmt, Dialect::Quartz)
+    }
+}
+
+#[cfg(feature = "time")]
+#[derive(Clone)]
+enum IterState {
+    Start(time::OffsetDateTime),
+    //Last returned point, split into date and time of day.
+    Next(time::Date, (u8, u8, u8), time::UtcOffset),
+    Done,
+}
+
+#[cfg(feature = "time")]
+#[derive(Clone)]
+///Iterator over points in time of [CronSchedule].
+///
+///Created by [CronSchedule::iter_from], [CronSchedule::occurrences_between] or, in descending order, by [CronSchedule::rev_iter_from].
+///
+///Available with `time` feature
+pub struct ScheduleIter<'a> {
+    schedule: &'a CronSchedule,
+    state: IterState,
+    rev: bool,
+    //Exclusive bound of iteration.
+    end: Option<time::OffsetDateTime>,
+}
+
+#[cfg(feature = "time")]
+impl ScheduleIter<'_> {
+    ///Returns point in time after last returned one.
+    fn next_after(&self, date: time::Date, (hour, minute, second): (u8, u8, u8)) -> Option<(time::Date, (u8, u8, u8))> {
+        //Within the same day only time needs to be advanced, as date is already known to match.
+        if let Some(time_of_day) = self.schedule.next_time_of_day(hour, minute, second + 1) {
+            return Some((date, time_of_day));
+        }
+
+        let (year, month, day) = date.to_calendar_date();
+        let (year, month, day) = self.schedule.next_date(year, month as u8, day + 1)?;
+        let date = time::Date::from_calendar_date(year, Month::from_num_asserted(month).into(), day).ok()?;
+        Some((date, self.schedule.next_time_of_day(0, 0, 0)?))
+    }
+
+    ///Returns point in time before last returned one.
+    fn prev_before(&self, date: time::Date, (hour, minute, second): (u8, u8, u8)) -> Option<(time::Date, (u8, u8, u8))> {
+        let time_of_day = match (hour, minute, second) {
+            (0, 0, 0) => None,
+            (hour, 0, 0) => self.schedule.prev_time_of_day(hour - 1, Minute::MAX, Second::MAX),
+            (hour, minute, 0) => self.schedule.prev_time_of_day(hour, minute - 1, Second::MAX),
+            (hour, minute, second) => self.schedule.prev_time_of_day(hour, minute, second - 1),
+        };
+        if let Some(time_of_day) = time_of_day {
+            return Some((date, time_of_day));
+        }
+
+        let (year, month, day) = date.to_calendar_date();
+        let (year, month, day) = self.schedule.prev_date(year, month as u8, day - 1)?;
+        let date = time::Date::from_calendar_date(year, Month::from_num_asserted(month).into(), day).ok()?;
+        Some((date, self.schedule.prev_time_of_day(Hour::MAX, Minute::MAX, Second::MAX)?))
+    }
+}
+
+#[cfg(feature = "time")]
+impl Iterator for ScheduleIter<'_> {
+    type Item = time::OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match self.state {
+            IterState::Start(time) => match self.rev {
+                true => self.schedule.try_prev_time_from(time),
+                false => self.schedule.try_next_time_from(time),
+            }.map(|next| (next.date(), next.to_hms(), next.offset())),
+            IterState::Next(date, time_of_day, offset) => match self.rev {
+                true => self.prev_before(date, time_of_day),
+                false => self.next_after(date, time_of_day),
+            }.map(|(date, time_of_day)| (date, time_of_day, offset)),
+            IterState::Done => None,
+        };
+
+        let next = next.and_then(|(date, time_of_day, offset)| {
+            let (hour, minute, second) = time_of_day;
+            let time = time::Time::from_hms(hour, minute, second).ok()?;
+            let time = time::PrimitiveDateTime::new(date, time).assume_offset(offset);
+            match self.end {
+                Some(end) if time >= end => None,
+                _ => Some((time, IterState::Next(date, time_of_day, offset))),
+            }
+        });
+
+        match next {
+            Some((time, state)) => {
+                self.state = state;
+                Some(time)
+            },
+            None => {
+                self.state = IterState::Done;
+                None
+            }
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl core::iter::FusedIterator for ScheduleIter<'_> {
+}
+
 #[inline]
 #[cfg(feature = "time")]
 ///Gets schedule after `time`.
@@ -1,14 +1,27 @@
 extern crate alloc;
 
-use crate::CronSchedule;
+use crate::{CronSchedule, DayMatching, ParseError, ParseOptions};
 
 use serde::ser::{Serialize, Serializer};
 use serde::de::{Deserialize, Deserializer};
 
+//Prefix of expression, which requires both day of month and day of week to match.
+const DAY_MATCHING_AND: &str = "@and ";
+
+fn parse(text: &str) -> Result<CronSchedule, ParseError> {
+    match text.trim_start().strip_prefix(DAY_MATCHING_AND) {
+        Some(text) => CronSchedule::parse_str_with(text, ParseOptions::new().day_matching(DayMatching::And)),
+        None => CronSchedule::parse_str(text),
+    }
+}
+
 impl Serialize for CronSchedule {
     #[inline]
     fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
-        ser.collect_str(self)
+        match self.day_matching() {
+            DayMatching::Or => ser.collect_str(self),
+            DayMatching::And => ser.collect_str(&format_args!("{}{}", DAY_MATCHING_AND, self)),
+        }
     }
 }
 
@@ -24,13 +37,13 @@ impl<'de> serde::de::Visitor<'de> for StrVisitor {
 
     #[inline]
     fn visit_str<E: serde::de::Error>(self, input: &str) -> Result<Self::Value, E> {
-        CronSchedule::parse_str(input).map_err(|err| serde::de::Error::custom(format_args!("Not a valid cron: {:?}", err)))
+        parse(input).map_err(|err| serde::de::Error::custom(format_args!("Not a valid cron: {:?}", err)))
     }
 
     #[inline]
     fn visit_bytes<E: serde::de::Error>(self, input: &[u8]) -> Result<Self::Value, E> {
         match core::str::from_utf8(input) {
-            Ok(text) => CronSchedule::parse_str(text).map_err(|err| serde::de::Error::custom(format_args!("Not a valid cron: {:?}", err))),
+            Ok(text) => parse(text).map_err(|err| serde::de::Error::custom(format_args!("Not a valid cron: {:?}", err))),
             Err(error) => Err(serde::de::Error::custom(error)),
         }
     }
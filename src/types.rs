@@ -1,4 +1,5 @@
-use crate::utils::IteratorExt;
+use crate::utils::{BitIter, DebugIter, IteratorExt, eq_ignore_ascii_case};
+use crate::{Dialect, ParseOptions, Values, Years, YearsMask};
 
 const ZERO_CHAR_BYTE: u8 = b'0';
 const EXPR_SPLIT: char = ',';
@@ -21,13 +22,18 @@ pub enum InvalidExpr {
     ///Indicates that specified range contains reversed values.
     InvalidRangeRev,
     ///Indicates that too many values are parsed. Indicates Internal Error of library.
-    ParserOverflow
+    ParserOverflow,
+    ///Indicates that years are neither evenly spaced nor fit within 64 steps, hence cannot be stored.
+    SparseYears,
 }
 
-#[cold]
-#[inline(never)]
-const fn parser_overflow() -> InvalidExpr {
-    InvalidExpr::ParserOverflow
+#[inline(always)]
+///Replaces Quartz's `?` (no specific value) with wildcard, as both match every day.
+fn no_specific_value(text: &str, options: ParseOptions) -> &str {
+    match (text, options.dialect) {
+        ("?", Dialect::Quartz | Dialect::Aws) => "*",
+        (text, _) => text,
+    }
 }
 
 macro_rules! impl_into_inner {
@@ -54,12 +60,16 @@ macro_rules! impl_into_inner {
 }
 
 impl_into_inner!(
+    Second unpack u8;
+    Second unpack usize;
     DayOfMonth unpack u8;
     DayOfMonth unpack usize;
     Minute unpack u8;
     Minute unpack usize;
     Hour unpack u8;
     Hour unpack usize;
+    Year unpack u16;
+    Year unpack usize;
 );
 impl_into_inner!(
     Month as u8;
@@ -68,71 +78,88 @@ impl_into_inner!(
     Day as usize;
 );
 
+//`$set` is type of resulting set of values.
 macro_rules! impl_from_expr {
-    ($text:expr) => {
+    ($set:ty; $text:expr, $options:expr) => {
+        impl_from_expr!($set; $text, $options, |_| Ok(false));
+    };
+    //`special` is invoked on each field first and returns `true` if field is consumed by it.
+    ($set:ty; $text:expr, $options:expr, $special:expr) => {
+        impl_from_expr!($set; $text, $options, $special, |_: &str, _, to| to);
+    };
+    //`range_end` is invoked with text and numeric values of range's end and start to adjust numeric value of range's end.
+    //Adjusted value is allowed to exceed MAX, in which case it wraps around.
+    ($set:ty; $text:expr, $options:expr, $special:expr, $range_end:expr) => {
         let text = $text;
-        let mut result = statiki::Array::new();
+        let options: ParseOptions = $options;
+        #[allow(unused_mut)]
+        let mut special = $special;
+        let mut result = <$set>::new();
+
+        let range_end = $range_end;
+        //Parses range, which is allowed to wrap around when options permit it.
+        let parse_range = |from, to| {
+            let from = Self::from_str(from, options, InvalidExpr::InvalidRange, InvalidExpr::InvalidRange)?.into();
+            let to = range_end(to, from, Self::from_str(to, options, InvalidExpr::InvalidRange, InvalidExpr::InvalidRange)?.into());
+
+            if from > to && !options.wrap_ranges {
+                Err(InvalidExpr::InvalidRangeRev)
+            } else {
+                Ok((from, to))
+            }
+        };
+        //Returns range values, going from MAX back to MIN, if range is reversed.
+        let range_values = |from, to, step| {
+            let len = match from <= to {
+                true => to - from + 1,
+                false => (Self::MAX - from + 1) + (to - Self::MIN + 1),
+            };
+
+            (0..len).step_by(step).map(move |offset| match from + offset {
+                num if num > Self::MAX => num - (Self::MAX - Self::MIN + 1),
+                num => num,
+            })
+        };
 
-        let mut fields = text.split(EXPR_SPLIT);
-        while let Some(field) = fields.next() {
-            if field == "*" {
-                if !result.is_empty() || fields.next().is_some() {
+        let mut fields = text.split(EXPR_SPLIT).enumerate();
+        while let Some((idx, field)) = fields.next() {
+            if special(field)? {
+                continue;
+            } else if field == "*" {
+                if idx != 0 || fields.next().is_some() {
                     return Err(InvalidExpr::InvalidWildCard);
                 }
 
                 for num in Self::MIN..=Self::MAX {
-                    if result.push(Self::from_num_asserted(num)).is_some() {
-                        return Err(parser_overflow());
-                    }
+                    result.insert(num);
                 }
 
             } else if let Some([init, step]) = field.split("/").collect_exact() {
-                let init: u8 = match init {
-                    "*" => Self::MIN,
-                    init => Self::from_str(init, InvalidExpr::InvalidStepValue, InvalidExpr::InvalidStepRange)?.into(),
+                let (init, end) = match init {
+                    "*" => (Self::MIN, Self::MAX),
+                    init => match init.split("-").collect_exact() {
+                        Some([from, to]) => parse_range(from, to)?,
+                        None => (Self::from_str(init, options, InvalidExpr::InvalidStepValue, InvalidExpr::InvalidStepRange)?.into(), Self::MAX),
+                    },
+                };
+                let step: usize = match step.parse() {
+                    Ok(step) if step > 0 && step <= Self::MAX as usize => step,
+                    Ok(_) => return Err(InvalidExpr::InvalidStepRange),
+                    Err(_) => return Err(InvalidExpr::InvalidStepValue),
                 };
-                let step: usize = Self::from_str(step, InvalidExpr::InvalidStepValue, InvalidExpr::InvalidStepRange)?.into();
-
-                if step == 0 {
-                    return Err(InvalidExpr::InvalidStepRange);
-                }
 
-                for num in (init..=Self::MAX).step_by(step) {
-                    let num = Self::from_num_asserted(num);
-                    if !result.contains(&num) {
-                        if result.push(num).is_some() {
-                            return Err(parser_overflow());
-                        }
-                    }
+                for num in range_values(init, end, step) {
+                    result.insert(num);
                 }
-
-                result.sort_unstable();
             } else if let Some([from, to]) = field.split("-").collect_exact() {
-                let from = Self::from_str(from, InvalidExpr::InvalidRange, InvalidExpr::InvalidRange)?;
-                let to = Self::from_str(to, InvalidExpr::InvalidRange, InvalidExpr::InvalidRange)?;
+                let (from, to) = parse_range(from, to)?;
 
-                if from > to {
-                    return Err(InvalidExpr::InvalidRangeRev);
+                for num in range_values(from, to, 1) {
+                    result.insert(num);
                 }
-
-                for num in from.into()..=to.into() {
-                    let num = Self::from_num_asserted(num);
-                    if !result.contains(&num) {
-                        if result.push(num).is_some() {
-                            return Err(parser_overflow());
-                        }
-                    }
-                }
-
-                result.sort_unstable();
             } else {
-                let num = Self::from_str(field, InvalidExpr::InvalidEntryValue, InvalidExpr::InvalidEntryRange)?;
-                if !result.contains(&num) {
-                    if result.push(num).is_some() {
-                        return Err(parser_overflow());
-                    }
-                    result.sort_unstable();
-                }
+                let num = Self::from_str(field, options, InvalidExpr::InvalidEntryValue, InvalidExpr::InvalidEntryRange)?;
+                result.insert(num.into());
             }
         }
 
@@ -142,7 +169,7 @@ macro_rules! impl_from_expr {
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-///Second of the minute.
+///Day of the month.
 ///
 ///# Allowed values:
 ///
@@ -172,7 +199,7 @@ impl DayOfMonth {
     }
 
     #[inline(always)]
-    fn from_str(text: &str, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+    fn from_str(text: &str, _: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
         match text.parse() {
             Ok(num) if num <= Self::MAX && num >= Self::MIN => Ok(Self(num)),
             Ok(_) => return Err(invalid_range),
@@ -180,9 +207,68 @@ impl DayOfMonth {
         }
     }
 
+    fn from_expr_list(text: &str, options: ParseOptions, special: impl FnMut(&str) -> Result<bool, InvalidExpr>) -> Result<Values<Self>, InvalidExpr> {
+        impl_from_expr!(Values<Self>; text, options, special);
+    }
+
     ///Creates instance from cron expression
-    pub fn from_expr(text: &str) -> Result<statiki::Array<Self, 31>, InvalidExpr> {
-        impl_from_expr!(text);
+    ///
+    ///In addition to regular values, following is accepted:
+    ///
+    ///- `L` - Last day of the month;
+    ///- `L-n` - `n` days before the last day of the month;
+    ///- `nW` - Weekday (Monday to Friday) nearest to the day `n` within the same month;
+    ///- `LW` - Last weekday of the month.
+    ///
+    ///With [Dialect::Quartz] or [Dialect::Aws] `?` is accepted as the same as `*`.
+    #[inline]
+    pub fn from_expr(text: &str) -> Result<DaysOfMonth, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    ///
+    ///Refer to [from_expr](#method.from_expr) for syntax.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<DaysOfMonth, InvalidExpr> {
+        let text = no_specific_value(text, options);
+        let mut last = 0u32;
+        let mut nearest = 0u32;
+        let mut last_weekday = false;
+
+        let days = Self::from_expr_list(text, options, |field| {
+            if let Some(day) = field.strip_suffix('W').or_else(|| field.strip_suffix('w')) {
+                match day {
+                    "L" | "l" => last_weekday = true,
+                    day => {
+                        let day = Self::from_str(day, options, InvalidExpr::InvalidEntryValue, InvalidExpr::InvalidEntryRange)?;
+                        nearest |= 1 << day.0;
+                    }
+                }
+
+                return Ok(true);
+            }
+
+            let offset = match field.strip_prefix('L').or_else(|| field.strip_prefix('l')) {
+                Some("") => 0,
+                Some(offset) => match offset.strip_prefix('-').map(str::parse) {
+                    Some(Ok(offset)) if offset <= Self::MAX - Self::MIN => offset,
+                    Some(Ok(_)) => return Err(InvalidExpr::InvalidEntryRange),
+                    Some(Err(_)) | None => return Err(InvalidExpr::InvalidEntryValue),
+                },
+                None => return Ok(false),
+            };
+
+            last |= 1 << offset;
+
+            Ok(true)
+        })?;
+
+        Ok(DaysOfMonth {
+            days: days.bits() as u32,
+            last,
+            nearest,
+            last_weekday,
+        })
     }
 }
 
@@ -193,6 +279,266 @@ impl core::fmt::Display for DayOfMonth {
     }
 }
 
+#[derive(Clone, PartialEq, Eq)]
+///Days of the month expression.
+pub struct DaysOfMonth {
+    //Bitmasks, where bit `n` stands for day `n` or offset `n`.
+    days: u32,
+    last: u32,
+    nearest: u32,
+    last_weekday: bool,
+}
+
+impl DaysOfMonth {
+    #[inline(always)]
+    ///Creates expression, matching only exact `days`.
+    pub(crate) const fn from_days(days: Values<DayOfMonth>) -> Self {
+        Self {
+            days: days.bits() as u32,
+            last: 0,
+            nearest: 0,
+            last_weekday: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns set of exact days.
+    pub fn days(&self) -> Values<DayOfMonth> {
+        Values::from_bits(self.days as u64)
+    }
+
+    #[inline(always)]
+    ///Returns number of exact days, same as `days().len()`.
+    pub fn len(&self) -> usize {
+        self.days.count_ones() as usize
+    }
+
+    #[inline(always)]
+    ///Returns whether there are no exact days, same as `days().is_empty()`.
+    pub fn is_empty(&self) -> bool {
+        self.days == 0
+    }
+
+    #[inline(always)]
+    ///Returns ordered list of offsets from the last day of the month.
+    ///
+    ///`0` stands for last day itself (i.e. `L`), while `n` stands for `L-n`.
+    pub fn last_day_offsets(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + Clone {
+        BitIter(self.last as u64)
+    }
+
+    #[inline(always)]
+    ///Returns set of days, nearest weekday to which is scheduled (i.e. `nW`).
+    pub fn nearest_weekdays(&self) -> Values<DayOfMonth> {
+        Values::from_bits(self.nearest as u64)
+    }
+
+    #[inline(always)]
+    ///Returns whether last weekday of the month is scheduled (i.e. `LW`).
+    pub fn last_weekday(&self) -> bool {
+        self.last_weekday
+    }
+
+    #[inline(always)]
+    ///Returns whether expression matches every day (i.e. `*`).
+    pub fn is_any(&self) -> bool {
+        self.days().is_all() && self.last == 0 && self.nearest == 0 && !self.last_weekday
+    }
+
+    ///Returns weekday (Monday to Friday) nearest to the `day`, without leaving the month.
+    fn nearest_weekday(day: u8, days_in_month: u8, first_weekday: u8) -> u8 {
+        match (first_weekday + day - 1) % 7 {
+            //Saturday moves to Friday, unless it is first day of month.
+            6 if day == 1 => day + 2,
+            6 => day - 1,
+            //Sunday moves to Monday, unless it is last day of month.
+            0 if day == days_in_month => day - 2,
+            0 => day + 1,
+            _ => day,
+        }
+    }
+
+    ///Returns first day of the month, starting from `day`, that matches expression.
+    ///
+    ///- `days_in_month` is used to resolve days relative to the end of the month.
+    ///- `first_weekday` is weekday of the first day in month as number of days from Sunday.
+    pub(crate) fn next_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        let mut result = self.days().next_from(day).filter(|day| *day <= days_in_month);
+        let mut add_candidate = |candidate: u8| match result {
+            Some(day) if day <= candidate => (),
+            _ => result = Some(candidate),
+        };
+
+        //The greatest fitting offset gives the closest day.
+        if let Some(offset) = BitIter(self.last as u64 & (u64::MAX >> (63 - (days_in_month - day)))).next_back() {
+            add_candidate(days_in_month - offset);
+        }
+
+        for nearest in self.nearest_weekdays().iter().take_while(|nearest| nearest.0 <= days_in_month) {
+            let nearest = Self::nearest_weekday(nearest.0, days_in_month, first_weekday);
+            if nearest >= day {
+                add_candidate(nearest);
+            }
+        }
+
+        if self.last_weekday {
+            let nearest = Self::nearest_weekday(days_in_month, days_in_month, first_weekday);
+            if nearest >= day {
+                add_candidate(nearest);
+            }
+        }
+
+        result
+    }
+
+    ///Returns last day of the month, up to `day`, that matches expression.
+    ///
+    ///Parameters are the same as in [next_day](#method.next_day), while `day` must not exceed `days_in_month`.
+    #[cfg(feature = "time")]
+    pub(crate) fn prev_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        let mut result = self.days().prev_from(day).filter(|day| *day > 0);
+        let mut add_candidate = |candidate: u8| match result {
+            Some(day) if day >= candidate => (),
+            _ => result = Some(candidate),
+        };
+
+        //The smallest fitting offset gives the closest day.
+        if let Some(offset) = BitIter(self.last as u64 & (u64::MAX << (days_in_month - day))).next() {
+            if offset < days_in_month {
+                add_candidate(days_in_month - offset);
+            }
+        }
+
+        for nearest in self.nearest_weekdays().iter().take_while(|nearest| nearest.0 <= days_in_month) {
+            let nearest = Self::nearest_weekday(nearest.0, days_in_month, first_weekday);
+            if nearest <= day {
+                add_candidate(nearest);
+            }
+        }
+
+        if self.last_weekday {
+            let nearest = Self::nearest_weekday(days_in_month, days_in_month, first_weekday);
+            if nearest <= day {
+                add_candidate(nearest);
+            }
+        }
+
+        result
+    }
+}
+
+impl core::fmt::Debug for DaysOfMonth {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_struct("DaysOfMonth")
+           .field("days", &self.days())
+           .field("last", &DebugIter(self.last_day_offsets()))
+           .field("nearest", &self.nearest_weekdays())
+           .field("last_weekday", &self.last_weekday)
+           .finish()
+    }
+}
+
+impl core::fmt::Display for DaysOfMonth {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_any() {
+            return fmt.write_str("*");
+        }
+
+        crate::utils::write_list(fmt, self.days())?;
+
+        let mut is_first = self.days == 0;
+        for offset in self.last_day_offsets() {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+            is_first = false;
+
+            match offset {
+                0 => fmt.write_str("L")?,
+                offset => fmt.write_fmt(format_args!("L-{}", offset))?,
+            }
+        }
+
+        for nearest in self.nearest_weekdays() {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+            is_first = false;
+
+            fmt.write_fmt(format_args!("{}W", nearest))?;
+        }
+
+        if self.last_weekday {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+
+            fmt.write_str("LW")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///Second of the minute.
+///
+///# Allowed values:
+///
+///- `0..=59`
+pub struct Second(u8);
+
+impl Second {
+    ///Min possible value.
+    pub const MIN: u8 = 0;
+    ///Max possible value.
+    pub const MAX: u8 = 59;
+    ///Expression name.
+    pub const NAME: &'static str = "Second";
+
+    ///Creates instance from numeric
+    pub(crate) const fn from_num_asserted(num: u8) -> Self {
+        Self(num)
+    }
+
+    ///Creates instance from numeric
+    pub const fn from_num(num: u8) -> Option<Self> {
+        if num <= Self::MAX {
+            Some(Self(num))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn from_str(text: &str, _: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+        match text.parse() {
+            Ok(num) if num <= Self::MAX => Ok(Self(num)),
+            Ok(_) => return Err(invalid_range),
+            Err(_) => return Err(invalid_val),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from cron expression
+    pub fn from_expr(text: &str) -> Result<Values<Self>, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<Values<Self>, InvalidExpr> {
+        impl_from_expr!(Values<Self>; text, options);
+    }
+}
+
+impl core::fmt::Display for Second {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.write_fmt(format_args!("{}", self.0))
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 ///Minute of the hour.
@@ -225,7 +571,7 @@ impl Minute {
     }
 
     #[inline(always)]
-    fn from_str(text: &str, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+    fn from_str(text: &str, _: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
         match text.parse() {
             Ok(num) if num <= Self::MAX => Ok(Self(num)),
             Ok(_) => return Err(invalid_range),
@@ -233,9 +579,15 @@ impl Minute {
         }
     }
 
+    #[inline]
     ///Creates instance from cron expression
-    pub fn from_expr(text: &str) -> Result<statiki::Array<Self, 60>, InvalidExpr> {
-        impl_from_expr!(text);
+    pub fn from_expr(text: &str) -> Result<Values<Self>, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<Values<Self>, InvalidExpr> {
+        impl_from_expr!(Values<Self>; text, options);
     }
 }
 
@@ -278,7 +630,7 @@ impl Hour {
     }
 
     #[inline(always)]
-    fn from_str(text: &str, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+    fn from_str(text: &str, _: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
         match text.parse() {
             Ok(num) if num <= Self::MAX => Ok(Self(num)),
             Ok(_) => return Err(invalid_range),
@@ -286,9 +638,15 @@ impl Hour {
         }
     }
 
+    #[inline]
     ///Creates instance from cron expression
-    pub fn from_expr(text: &str) -> Result<statiki::Array<Self, 24>, InvalidExpr> {
-        impl_from_expr!(text);
+    pub fn from_expr(text: &str) -> Result<Values<Self>, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<Values<Self>, InvalidExpr> {
+        impl_from_expr!(Values<Self>; text, options);
     }
 }
 
@@ -306,7 +664,9 @@ impl core::fmt::Display for Hour {
 ///# Allowed values:
 ///
 ///- `0..=6`
+///- `7` - Sunday
 ///- `SUN..=SAT` - case is ignored
+///- `SUNDAY..=SATURDAY` - case is ignored
 pub enum Day {
     ///Sunday
     Sunday = 0,
@@ -320,7 +680,7 @@ pub enum Day {
     Thursday = 4,
     ///Friday
     Friday = 5,
-    ///Friday
+    ///Saturday
     Saturday = 6,
 }
 
@@ -368,57 +728,156 @@ impl Day {
         }
     }
 
+    ///Returns full English name.
+    #[inline(always)]
+    pub const fn to_name(self) -> &'static str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        }
+    }
+
+    ///Accepts either abbreviation or full name.
     const fn from_textual_repr(text: &[u8]) -> Option<Self> {
-        let text = [
+        if text.len() < 3 {
+            return None;
+        }
+
+        let abbr = [
             text[0].to_ascii_uppercase(),
             text[1].to_ascii_uppercase(),
             text[2].to_ascii_uppercase(),
         ];
 
-        return match &text {
-            b"SUN" => Some(Self::Sunday),
-            b"MON" => Some(Self::Monday),
-            b"TUE" => Some(Self::Tuesday),
-            b"WED" => Some(Self::Wednesday),
-            b"THU" => Some(Self::Thursday),
-            b"FRI" => Some(Self::Friday),
-            b"SAT" => Some(Self::Saturday),
-            _ => None
+        let (day, name): (Self, &[u8]) = match &abbr {
+            b"SUN" => (Self::Sunday, b"SUNDAY"),
+            b"MON" => (Self::Monday, b"MONDAY"),
+            b"TUE" => (Self::Tuesday, b"TUESDAY"),
+            b"WED" => (Self::Wednesday, b"WEDNESDAY"),
+            b"THU" => (Self::Thursday, b"THURSDAY"),
+            b"FRI" => (Self::Friday, b"FRIDAY"),
+            b"SAT" => (Self::Saturday, b"SATURDAY"),
+            _ => return None
         };
+
+        if text.len() == 3 || eq_ignore_ascii_case(text, name) {
+            Some(day)
+        } else {
+            None
+        }
     }
 
     ///Parses day from the string accordingly to allowed values.
     pub const fn from_bytes(text: &[u8]) -> Option<Self> {
         if text.len() == 1 {
             let num = text[0];
-            if num >= ZERO_CHAR_BYTE {
+            if num == b'7' {
+                return Some(Self::Sunday);
+            } else if num >= ZERO_CHAR_BYTE {
                 return Self::from_num(num - ZERO_CHAR_BYTE);
             } else {
                 return None
             }
-        } else if text.len() == 3 {
-            return Self::from_textual_repr(text)
         }
 
-        None
+        Self::from_textual_repr(text)
     }
 
     #[inline(always)]
-    fn from_str(text: &str, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
-        match text.parse() {
-            Ok(num) if num <= Self::MAX => Ok(Self::from_num_asserted(num)),
-            Ok(_) => Err(invalid_range),
-            Err(_) if text.len() == 3 => match Self::from_textual_repr(text.as_bytes()) {
-                Some(num) => Ok(num),
-                None => Err(invalid_val)
+    fn from_str(text: &str, options: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+        match options.dialect {
+            Dialect::Cron => match text.parse() {
+                Ok(num) if num <= Self::MAX => Ok(Self::from_num_asserted(num)),
+                Ok(7) => Ok(Self::Sunday),
+                Ok(_) => Err(invalid_range),
+                Err(_) => match Self::from_textual_repr(text.as_bytes()) {
+                    Some(num) => Ok(num),
+                    None => Err(invalid_val)
+                },
+            },
+            //Quartz counts days from `1` (Sunday) to `7` (Saturday), while single `L` stands for Saturday.
+            Dialect::Quartz | Dialect::Aws => match text.parse::<u8>() {
+                Ok(num) if num >= 1 && num <= Self::MAX + 1 => Ok(Self::from_num_asserted(num - 1)),
+                Ok(_) => Err(invalid_range),
+                Err(_) if text == "L" || text == "l" => Ok(Self::Saturday),
+                Err(_) => match Self::from_textual_repr(text.as_bytes()) {
+                    Some(num) => Ok(num),
+                    None => Err(invalid_val)
+                },
             },
-            Err(_) => Err(invalid_val),
         }
     }
 
+    fn from_expr_list(text: &str, options: ParseOptions, special: impl FnMut(&str) -> Result<bool, InvalidExpr>) -> Result<Values<Self>, InvalidExpr> {
+        let sunday = match options.dialect {
+            Dialect::Cron => "0",
+            Dialect::Quartz | Dialect::Aws => "1",
+        };
+        //Sunday at the end of range is end of week, unless it is written as number of the first day.
+        //`7` is always end of week, so that `0-7` is the whole week.
+        impl_from_expr!(Values<Self>; text, options, special, |text: &str, from: u8, to: u8| match to {
+            0 if text == "7" || (from != 0 && text != sunday) => Self::MAX + 1,
+            to => to,
+        });
+    }
+
     ///Creates instance from cron expression
-    pub fn from_expr(text: &str) -> Result<statiki::Array<Self, 7>, InvalidExpr> {
-        impl_from_expr!(text);
+    ///
+    ///In addition to regular values, following is accepted:
+    ///
+    ///- `<day>#<n>` - `n`th (`1..=5`) day of week in month (e.g. `FRI#3` for third Friday);
+    ///- `<day>L` - Last day of week in month (e.g. `5L` for last Friday).
+    ///
+    ///With [Dialect::Quartz] or [Dialect::Aws] days are numbered from `1` (Sunday) to `7` (Saturday),
+    ///single `L` stands for Saturday and `?` is the same as `*`.
+    #[inline]
+    pub fn from_expr(text: &str) -> Result<DaysOfWeek, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    ///
+    ///Refer to [from_expr](#method.from_expr) for syntax.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<DaysOfWeek, InvalidExpr> {
+        let text = no_specific_value(text, options);
+        let mut nth = [0u8; 5];
+        let mut last = 0u8;
+
+        let days = Self::from_expr_list(text, options, |field| {
+            if let Some([day, num]) = field.split('#').collect_exact() {
+                let day = Self::from_str(day, options, InvalidExpr::InvalidEntryValue, InvalidExpr::InvalidEntryRange)?;
+                let num = match num.parse::<u8>() {
+                    Ok(num) if num >= 1 && num <= 5 => num,
+                    Ok(_) => return Err(InvalidExpr::InvalidEntryRange),
+                    Err(_) => return Err(InvalidExpr::InvalidEntryValue),
+                };
+
+                nth[num as usize - 1] |= 1 << day as u8;
+
+                return Ok(true);
+            }
+
+            let day = match field.strip_suffix('L').or_else(|| field.strip_suffix('l')) {
+                //Single `L` is regular value, when dialect permits it.
+                Some("") | None => return Ok(false),
+                Some(day) => Self::from_str(day, options, InvalidExpr::InvalidEntryValue, InvalidExpr::InvalidEntryRange)?,
+            };
+
+            last |= 1 << day as u8;
+
+            Ok(true)
+        })?;
+
+        Ok(DaysOfWeek {
+            days: days.bits() as u8,
+            nth,
+            last,
+        })
     }
 }
 
@@ -429,6 +888,183 @@ impl core::fmt::Display for Day {
     }
 }
 
+#[derive(Clone, PartialEq, Eq)]
+///Days of the week expression.
+pub struct DaysOfWeek {
+    //Bitmasks, where bit `n` stands for day `n`.
+    days: u8,
+    //Element `n` stands for days, which are scheduled on `n + 1` week of the month.
+    nth: [u8; 5],
+    last: u8,
+}
+
+impl DaysOfWeek {
+    #[inline(always)]
+    ///Creates expression, matching `days` every week.
+    pub(crate) const fn from_days(days: Values<Day>) -> Self {
+        Self {
+            days: days.bits() as u8,
+            nth: [0; 5],
+            last: 0,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns set of days, matching every week.
+    pub fn days(&self) -> Values<Day> {
+        Values::from_bits(self.days as u64)
+    }
+
+    #[inline(always)]
+    ///Returns number of days, matching every week, same as `days().len()`.
+    pub fn len(&self) -> usize {
+        self.days.count_ones() as usize
+    }
+
+    #[inline(always)]
+    ///Returns whether there are no days, matching every week, same as `days().is_empty()`.
+    pub fn is_empty(&self) -> bool {
+        self.days == 0
+    }
+
+    #[inline]
+    ///Returns ordered list of days of week with their number within month (i.e. `<day>#<n>`).
+    pub fn nth_days(&self) -> impl Iterator<Item = (Day, u8)> + Clone {
+        let nth = self.nth;
+        BitIter(Values::<Day>::ALL).flat_map(move |day| {
+            (1..=nth.len() as u8).filter(move |num| nth[*num as usize - 1] & (1 << day) != 0)
+                                 .map(move |num| (Day::from_num_asserted(day), num))
+        })
+    }
+
+    #[inline(always)]
+    ///Returns set of days of week, last of which within month is scheduled (i.e. `<day>L`).
+    pub fn last_days(&self) -> Values<Day> {
+        Values::from_bits(self.last as u64)
+    }
+
+    #[inline(always)]
+    ///Returns whether expression matches every day (i.e. `*`).
+    pub fn is_any(&self) -> bool {
+        self.days().is_all() && self.nth == [0; 5] && self.last == 0
+    }
+
+    ///Returns first day of the month, starting from `day`, that matches expression.
+    ///
+    ///- `days_in_month` is used to resolve days relative to the end of the month.
+    ///- `first_weekday` is weekday of the first day in month as number of days from Sunday.
+    pub(crate) fn next_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        let weekday = (first_weekday + day - 1) % 7;
+        //Rotate week, so that it starts from `weekday`.
+        let days = self.days as u16;
+        let days = ((days | days << 7) >> weekday) & 0x7F;
+        let mut result = match days {
+            0 => None,
+            days => Some(day + days.trailing_zeros() as u8).filter(|day| *day <= days_in_month),
+        };
+        let mut add_candidate = |candidate: u8| match result {
+            Some(day) if day <= candidate => (),
+            _ => result = Some(candidate),
+        };
+
+        for (day_w, num) in self.nth_days() {
+            let first = 1 + (day_w as u8 + 7 - first_weekday) % 7;
+            let candidate = first + 7 * (num - 1);
+            if candidate >= day && candidate <= days_in_month {
+                add_candidate(candidate);
+            }
+        }
+
+        let last_weekday = (first_weekday + days_in_month - 1) % 7;
+        for day_w in self.last_days() {
+            let candidate = days_in_month - (last_weekday + 7 - day_w as u8) % 7;
+            if candidate >= day {
+                add_candidate(candidate);
+            }
+        }
+
+        result
+    }
+
+    ///Returns last day of the month, up to `day`, that matches expression.
+    ///
+    ///Parameters are the same as in [next_day](#method.next_day), while `day` must not exceed `days_in_month`.
+    #[cfg(feature = "time")]
+    pub(crate) fn prev_day(&self, day: u8, days_in_month: u8, first_weekday: u8) -> Option<u8> {
+        let weekday = (first_weekday + day - 1) % 7;
+        //Rotate week, so that it ends at `weekday`.
+        let days = self.days as u16;
+        let days = (((days | days << 7) >> (weekday + 1)) & 0x7F) as u8;
+        let mut result = match days {
+            0 => None,
+            days => day.checked_sub(days.leading_zeros() as u8 - 1).filter(|day| *day > 0),
+        };
+        let mut add_candidate = |candidate: u8| match result {
+            Some(day) if day >= candidate => (),
+            _ => result = Some(candidate),
+        };
+
+        for (day_w, num) in self.nth_days() {
+            let first = 1 + (day_w as u8 + 7 - first_weekday) % 7;
+            let candidate = first + 7 * (num - 1);
+            if candidate <= day {
+                add_candidate(candidate);
+            }
+        }
+
+        let last_weekday = (first_weekday + days_in_month - 1) % 7;
+        for day_w in self.last_days() {
+            let candidate = days_in_month - (last_weekday + 7 - day_w as u8) % 7;
+            if candidate <= day {
+                add_candidate(candidate);
+            }
+        }
+
+        result
+    }
+}
+
+impl core::fmt::Debug for DaysOfWeek {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_struct("DaysOfWeek")
+           .field("days", &self.days())
+           .field("nth", &DebugIter(self.nth_days()))
+           .field("last", &self.last_days())
+           .finish()
+    }
+}
+
+impl core::fmt::Display for DaysOfWeek {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_any() {
+            return fmt.write_str("*");
+        }
+
+        crate::utils::write_list(fmt, self.days())?;
+
+        let mut is_first = self.days == 0;
+        for (day, num) in self.nth_days() {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+            is_first = false;
+
+            fmt.write_fmt(format_args!("{}#{}", day, num))?;
+        }
+
+        for day in self.last_days() {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+            is_first = false;
+
+            fmt.write_fmt(format_args!("{}L", day))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 ///Month of the year.
@@ -437,6 +1073,7 @@ impl core::fmt::Display for Day {
 ///
 ///- `1..=12`
 ///- `JAN..=DEC` - case is ignored
+///- `JANUARY..=DECEMBER` - case is ignored
 pub enum Month {
     ///January
     January = 1,
@@ -529,28 +1166,58 @@ impl Month {
         }
     }
 
+    ///Returns full English name.
+    #[inline(always)]
+    pub const fn to_name(self) -> &'static str {
+        match self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
+    }
+
+    ///Accepts either abbreviation or full name.
     const fn from_textual_repr(text: &[u8]) -> Option<Self> {
-        let text = [
+        if text.len() < 3 {
+            return None;
+        }
+
+        let abbr = [
             text[0].to_ascii_uppercase(),
             text[1].to_ascii_uppercase(),
             text[2].to_ascii_uppercase(),
         ];
 
-        return match &text {
-            b"JAN" => Some(Self::January),
-            b"FEB" => Some(Self::February),
-            b"MAR" => Some(Self::March),
-            b"APR" => Some(Self::April),
-            b"MAY" => Some(Self::May),
-            b"JUN" => Some(Self::June),
-            b"JUL" => Some(Self::July),
-            b"AUG" => Some(Self::August),
-            b"SEP" => Some(Self::September),
-            b"OCT" => Some(Self::October),
-            b"NOV" => Some(Self::November),
-            b"DEC" => Some(Self::December),
-            _ => None
+        let (month, name): (Self, &[u8]) = match &abbr {
+            b"JAN" => (Self::January, b"JANUARY"),
+            b"FEB" => (Self::February, b"FEBRUARY"),
+            b"MAR" => (Self::March, b"MARCH"),
+            b"APR" => (Self::April, b"APRIL"),
+            b"MAY" => (Self::May, b"MAY"),
+            b"JUN" => (Self::June, b"JUNE"),
+            b"JUL" => (Self::July, b"JULY"),
+            b"AUG" => (Self::August, b"AUGUST"),
+            b"SEP" => (Self::September, b"SEPTEMBER"),
+            b"OCT" => (Self::October, b"OCTOBER"),
+            b"NOV" => (Self::November, b"NOVEMBER"),
+            b"DEC" => (Self::December, b"DECEMBER"),
+            _ => return None
         };
+
+        if text.len() == 3 || eq_ignore_ascii_case(text, name) {
+            Some(month)
+        } else {
+            None
+        }
     }
 
     ///Parses day from the string accordingly to allowed values.
@@ -571,29 +1238,32 @@ impl Month {
                 b"12" => Some(Self::December),
                 _ => None,
             };
-        } else if text.len() == 3 {
-            return Self::from_textual_repr(text);
         }
 
-        None
+        Self::from_textual_repr(text)
     }
 
     #[inline(always)]
-    fn from_str(text: &str, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+    fn from_str(text: &str, _: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
         match text.parse() {
             Ok(num) if num <= Self::MAX && num >= Self::MIN => Ok(Self::from_num_asserted(num)),
             Ok(_) => Err(invalid_range),
-            Err(_) if text.len() == 3 => match Self::from_textual_repr(text.as_bytes()) {
+            Err(_) => match Self::from_textual_repr(text.as_bytes()) {
                 Some(num) => Ok(num),
                 None => Err(invalid_val)
             },
-            Err(_) => Err(invalid_val),
         }
     }
 
+    #[inline]
     ///Creates instance from cron expression
-    pub fn from_expr(text: &str) -> Result<statiki::Array<Self, 12>, InvalidExpr> {
-        impl_from_expr!(text);
+    pub fn from_expr(text: &str) -> Result<Values<Self>, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<Values<Self>, InvalidExpr> {
+        impl_from_expr!(Values<Self>; text, options);
     }
 }
 
@@ -603,3 +1273,78 @@ impl core::fmt::Display for Month {
         fmt.write_str(self.to_textual_repr())
     }
 }
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///Year.
+///
+///# Allowed values:
+///
+///- `1970..=2199`
+///- `1970..=2099` in [Dialect::Quartz]
+pub struct Year(u16);
+
+impl Year {
+    ///Min possible value.
+    pub const MIN: u16 = 1970;
+    ///Max possible value.
+    pub const MAX: u16 = 2199;
+    ///Expression name.
+    pub const NAME: &'static str = "Year";
+    //Max possible value in Quartz dialect.
+    const QUARTZ_MAX: u16 = 2099;
+
+    ///Creates instance from numeric
+    pub(crate) const fn from_num_asserted(num: u16) -> Self {
+        Self(num)
+    }
+
+    ///Creates instance from numeric
+    pub const fn from_num(num: u16) -> Option<Self> {
+        if num >= Self::MIN && num <= Self::MAX {
+            Some(Self(num))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn from_str(text: &str, options: ParseOptions, invalid_val: InvalidExpr, invalid_range: InvalidExpr) -> Result<Self, InvalidExpr> {
+        let max = match options.dialect {
+            Dialect::Quartz => Self::QUARTZ_MAX,
+            Dialect::Cron | Dialect::Aws => Self::MAX,
+        };
+        match text.parse() {
+            Ok(num) if num <= max && num >= Self::MIN => Ok(Self(num)),
+            Ok(_) => return Err(invalid_range),
+            Err(_) => return Err(invalid_val),
+        }
+    }
+
+    #[inline]
+    ///Creates instance from cron expression
+    pub fn from_expr(text: &str) -> Result<Years, InvalidExpr> {
+        Self::from_expr_with(text, ParseOptions::new())
+    }
+
+    ///Creates instance from cron expression, using provided `options`.
+    pub fn from_expr_with(text: &str, options: ParseOptions) -> Result<Years, InvalidExpr> {
+        let mut mask = Self::from_expr_mask(text, options)?;
+        if options.dialect == Dialect::Quartz {
+            //Steps without end (e.g. `2026/20`) go up to the last year of Quartz.
+            mask.truncate(Self::QUARTZ_MAX);
+        }
+        Years::from_mask(&mask).ok_or(InvalidExpr::SparseYears)
+    }
+
+    fn from_expr_mask(text: &str, options: ParseOptions) -> Result<YearsMask, InvalidExpr> {
+        impl_from_expr!(YearsMask; text, options);
+    }
+}
+
+impl core::fmt::Display for Year {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.write_fmt(format_args!("{}", self.0))
+    }
+}
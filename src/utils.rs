@@ -1,4 +1,4 @@
-use core::{ptr, mem};
+use core::{ptr, mem, fmt, iter};
 
 pub trait IteratorExt: Iterator {
     ///Returns `None` if iterator doesn't contain exactly `S` element
@@ -27,3 +27,220 @@ pub trait IteratorExt: Iterator {
 
 impl<T: Iterator> IteratorExt for T {
 }
+
+///Compares `text` with uppercase `expected`, ignoring case of `text`.
+pub const fn eq_ignore_ascii_case(text: &[u8], expected: &[u8]) -> bool {
+    if text.len() != expected.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < text.len() {
+        if text[idx].to_ascii_uppercase() != expected[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+
+    true
+}
+
+#[inline]
+///Returns whether `year` is leap year in proleptic Gregorian calendar.
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+///Returns number of days in `month` (`1..=12`) of `year`.
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Clone)]
+///Iterator over indexes of set bits in ascending order.
+pub struct BitIter(pub u64);
+
+impl Iterator for BitIter {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0 {
+            0 => None,
+            bits => {
+                self.0 &= bits - 1;
+                Some(bits.trailing_zeros() as u8)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.0 {
+            0 => None,
+            bits => {
+                let idx = 63 - bits.leading_zeros();
+                self.0 &= !(1 << idx);
+                Some(idx as u8)
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for BitIter {
+}
+
+impl core::iter::FusedIterator for BitIter {
+}
+
+#[derive(Clone)]
+///Iterator over runs of consecutive values as `(first, last)`, out of ascending values.
+pub struct Runs<I: Iterator<Item = u16>>(iter::Peekable<I>);
+
+impl<I: Iterator<Item = u16>> Runs<I> {
+    #[inline(always)]
+    pub fn new(values: I) -> Self {
+        Self(values.peekable())
+    }
+}
+
+impl<I: Iterator<Item = u16>> Iterator for Runs<I> {
+    type Item = (u16, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.0.next()?;
+        let mut last = first;
+        while self.0.next_if_eq(&(last + 1)).is_some() {
+            last += 1;
+        }
+
+        Some((first, last))
+    }
+}
+
+#[derive(Clone, Copy)]
+///Values with the same difference between each other, as in `a-b/n`.
+pub struct Step {
+    pub first: u16,
+    pub last: u16,
+    pub step: u16,
+}
+
+impl Step {
+    ///Detects step within ascending values, which requires at least 2 values.
+    pub fn detect(mut values: impl Iterator<Item = u16>) -> Option<Self> {
+        let first = values.next()?;
+        let mut last = values.next()?;
+        let step = last - first;
+        if step < 2 {
+            return None;
+        }
+
+        for value in values {
+            if value - last != step {
+                return None;
+            }
+            last = value;
+        }
+
+        Some(Self {
+            first,
+            last,
+            step,
+        })
+    }
+
+    #[inline(always)]
+    ///Returns number of values.
+    pub fn len(&self) -> u16 {
+        (self.last - self.first) / self.step + 1
+    }
+
+    #[inline(always)]
+    ///Returns whether step covers whole range `min..=max`, as in `*/n`.
+    pub fn is_full(&self, min: u16, max: u16) -> bool {
+        self.first == min && self.last + self.step > max
+    }
+}
+
+///Wrapper to debug format iterator as list.
+pub struct DebugIter<I>(pub I);
+
+impl<I: Iterator + Clone> fmt::Debug for DebugIter<I> where I::Item: fmt::Debug {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+///Returns weekday of date in proleptic Gregorian calendar as number of days from Sunday.
+pub const fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    const MONTH_OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+    let year = match month < 3 {
+        true => year - 1,
+        false => year,
+    };
+    let days = year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + MONTH_OFFSETS[month as usize - 1] + day as i32;
+    days.rem_euclid(7) as u8
+}
+
+///Writes ordered list of values, grouping consecutive values into ranges.
+pub fn write_list<T: Copy + PartialEq + Into<usize> + fmt::Display>(fmt: &mut fmt::Formatter<'_>, elems: impl IntoIterator<Item = T>) -> fmt::Result {
+    let mut elems = elems.into_iter();
+    let mut start = match elems.next() {
+        Some(start) => start,
+        None => return Ok(()),
+    };
+
+    let mut is_first = true;
+    let mut end = start;
+    let mut prev: usize = start.into();
+
+    while let Some(elem) = elems.next() {
+        let elem_repr: usize = elem.into();
+
+        if (prev + 1) == elem_repr {
+            end = elem;
+        } else {
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+
+            is_first = false;
+            if start == end {
+                fmt.write_fmt(format_args!("{}", start))?;
+            } else {
+                fmt.write_fmt(format_args!("{}-{}", start, end))?;
+            }
+
+            start = elem;
+            end = elem;
+        }
+
+        prev = end.into();
+    }
+
+    if !is_first {
+        fmt.write_str(",")?;
+    }
+
+    if start == end {
+        fmt.write_fmt(format_args!("{}", start))
+    } else {
+        fmt.write_fmt(format_args!("{}-{}", start, end))
+    }
+}
@@ -0,0 +1,428 @@
+use core::{fmt, marker};
+
+use crate::utils::BitIter;
+use crate::{Second, Minute, Hour, DayOfMonth, Month, Day, Year};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+///Set of field's values.
+///
+///Stored as bitmask, where each bit stands for single value, and iterated in ascending order.
+pub struct Values<T> {
+    bits: u64,
+    _type: marker::PhantomData<T>,
+}
+
+#[derive(Clone)]
+///Iterator over [Values] in ascending order.
+pub struct ValuesIter<T> {
+    bits: BitIter,
+    _type: marker::PhantomData<T>,
+}
+
+impl<T> Values<T> {
+    #[inline(always)]
+    ///Creates empty set.
+    pub(crate) const fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    #[inline(always)]
+    ///Creates set from bitmask, where bit `n` stands for value `n`.
+    pub(crate) const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits,
+            _type: marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns bitmask, where bit `n` stands for value `n`.
+    pub(crate) const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline(always)]
+    ///Adds value to the set.
+    pub(crate) fn insert(&mut self, num: u8) {
+        self.bits |= 1 << num;
+    }
+
+    #[inline(always)]
+    ///Returns number of values.
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    #[inline(always)]
+    ///Returns whether set has no values.
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    #[inline]
+    ///Returns smallest value within set, that is not less than `num`.
+    pub(crate) fn next_from(&self, num: u8) -> Option<u8> {
+        match num < 64 {
+            true => BitIter(self.bits & (u64::MAX << num)).next(),
+            false => None,
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[inline]
+    ///Returns greatest value within set, that is not greater than `num`.
+    pub(crate) fn prev_from(&self, num: u8) -> Option<u8> {
+        BitIter(self.bits & (u64::MAX >> (63 - core::cmp::min(num, 63)))).next_back()
+    }
+}
+
+macro_rules! impl_values {
+    ($($ty:ident,)+) => {
+        $(
+            impl Values<$ty> {
+                ///Bitmask of all allowed values.
+                pub(crate) const ALL: u64 = (u64::MAX >> (63 - $ty::MAX)) & (u64::MAX << $ty::MIN);
+
+                #[inline(always)]
+                ///Returns whether set has every allowed value.
+                pub const fn is_all(&self) -> bool {
+                    self.bits == Self::ALL
+                }
+
+                #[inline(always)]
+                ///Returns whether `value` is within set.
+                pub fn contains(&self, value: $ty) -> bool {
+                    let num: u8 = value.into();
+                    self.bits & (1 << num) != 0
+                }
+
+                #[inline(always)]
+                ///Returns iterator over values in ascending order.
+                pub fn iter(&self) -> ValuesIter<$ty> {
+                    ValuesIter {
+                        bits: BitIter(self.bits),
+                        _type: marker::PhantomData,
+                    }
+                }
+            }
+
+            impl IntoIterator for Values<$ty> {
+                type Item = $ty;
+                type IntoIter = ValuesIter<$ty>;
+
+                #[inline(always)]
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter()
+                }
+            }
+
+            impl fmt::Debug for Values<$ty> {
+                #[inline]
+                fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt.debug_list().entries(self.iter()).finish()
+                }
+            }
+
+            impl Iterator for ValuesIter<$ty> {
+                type Item = $ty;
+
+                #[inline(always)]
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.bits.next().map($ty::from_num_asserted)
+                }
+
+                #[inline(always)]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.bits.size_hint()
+                }
+            }
+
+            impl DoubleEndedIterator for ValuesIter<$ty> {
+                #[inline(always)]
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    self.bits.next_back().map($ty::from_num_asserted)
+                }
+            }
+
+            impl ExactSizeIterator for ValuesIter<$ty> {
+            }
+
+            impl core::iter::FusedIterator for ValuesIter<$ty> {
+            }
+        )+
+    };
+}
+
+impl_values!(
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    Day,
+);
+
+const YEAR_WORDS: usize = ((Year::MAX - Year::MIN) as usize + 64) / 64;
+
+#[derive(Clone, Copy)]
+///Bitmask of years, where bit `n` stands for year `Year::MIN + n`, which is used to collect years before they are stored as [Years].
+pub(crate) struct YearsMask {
+    bits: [u64; YEAR_WORDS],
+}
+
+impl YearsMask {
+    #[inline(always)]
+    ///Creates empty set.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bits: [0; YEAR_WORDS],
+        }
+    }
+
+    #[inline(always)]
+    ///Adds year to the set.
+    pub(crate) fn insert(&mut self, num: u16) {
+        let idx = (num - Year::MIN) as usize;
+        self.bits[idx / 64] |= 1 << (idx % 64);
+    }
+
+    ///Removes years after `max`.
+    pub(crate) fn truncate(&mut self, max: u16) {
+        let idx = (max - Year::MIN) as usize + 1;
+        for (word, bits) in self.bits.iter_mut().enumerate().skip(idx / 64) {
+            match word == idx / 64 {
+                true => *bits &= !(u64::MAX << (idx % 64)),
+                false => *bits = 0,
+            }
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.bits.iter().enumerate().flat_map(|(word, bits)| BitIter(*bits).map(move |idx| Year::MIN + (word * 64) as u16 + idx as u16))
+    }
+}
+
+const fn gcd(mut a: u16, mut b: u16) -> u16 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+///Set of years.
+///
+///Stored either as evenly spaced years `first..=last` with common step, or as bitmask of up to 64 years spaced by common step.
+///Other sets cannot be stored and are rejected with [InvalidExpr::SparseYears](crate::InvalidExpr::SparseYears).
+///
+///Iterated in ascending order.
+pub struct Years {
+    //`0` in empty set.
+    first: u16,
+    step: u16,
+    last: u16,
+    //When not `0`, bit `n` stands for year `first + n * step`.
+    //Otherwise every year `first, first + step, ..., last` is within set.
+    bits: u64,
+}
+
+impl Years {
+    #[inline(always)]
+    ///Creates empty set.
+    pub(crate) const fn new() -> Self {
+        Self {
+            first: 0,
+            step: 0,
+            last: 0,
+            bits: 0,
+        }
+    }
+
+    ///Creates set out of `mask`, if it can be stored.
+    pub(crate) fn from_mask(mask: &YearsMask) -> Option<Self> {
+        let mut years = mask.iter();
+        let first = match years.next() {
+            Some(first) => first,
+            None => return Some(Self::new()),
+        };
+        let (last, step, len) = years.fold((first, 0, 1), |(_, step, len), year| (year, gcd(year - first, step), len + 1));
+        let step = core::cmp::max(step, 1);
+
+        let mut result = Self {
+            first,
+            step,
+            last,
+            bits: 0,
+        };
+        if (last - first) / step + 1 == len {
+            Some(result)
+        } else if (last - first) / step < 64 {
+            for year in mask.iter() {
+                result.bits |= 1 << ((year - first) / step);
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns number of years.
+    pub fn len(&self) -> usize {
+        match (self.first, self.bits) {
+            (0, _) => 0,
+            (_, 0) => ((self.last - self.first) / self.step + 1) as usize,
+            (_, bits) => bits.count_ones() as usize,
+        }
+    }
+
+    #[inline]
+    ///Returns whether set has no years.
+    pub fn is_empty(&self) -> bool {
+        self.first == 0
+    }
+
+    #[inline]
+    ///Returns whether set has every allowed year.
+    pub fn is_all(&self) -> bool {
+        self.first == Year::MIN && self.last == Year::MAX && self.step == 1 && self.bits == 0
+    }
+
+    #[inline]
+    ///Returns whether `year` is within set.
+    pub fn contains(&self, year: Year) -> bool {
+        let num: u16 = year.into();
+        match num.checked_sub(self.first) {
+            Some(offset) if !self.is_empty() && num <= self.last && offset % self.step == 0 => self.bits == 0 || self.bits & (1 << (offset / self.step)) != 0,
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns smallest year within set, that is not less than `num`.
+    pub(crate) fn next_from(&self, num: u16) -> Option<Year> {
+        if self.is_empty() || num > self.last {
+            return None;
+        }
+
+        let idx = match num.checked_sub(self.first) {
+            Some(offset) => offset.div_ceil(self.step),
+            None => 0,
+        };
+        let idx = match self.bits {
+            0 => idx,
+            bits => (bits & (u64::MAX << idx)).trailing_zeros() as u16,
+        };
+        Some(Year::from_num_asserted(self.first + idx * self.step))
+    }
+
+    #[cfg(feature = "time")]
+    ///Returns greatest year within set, that is not greater than `num`.
+    pub(crate) fn prev_from(&self, num: u16) -> Option<Year> {
+        if self.is_empty() || num < self.first {
+            return None;
+        }
+
+        let idx = (core::cmp::min(num, self.last) - self.first) / self.step;
+        let idx = match self.bits {
+            0 => idx,
+            bits => 63 - (bits & (u64::MAX >> (63 - idx))).leading_zeros() as u16,
+        };
+        Some(Year::from_num_asserted(self.first + idx * self.step))
+    }
+
+    #[inline(always)]
+    ///Returns iterator over years in ascending order.
+    pub fn iter(&self) -> YearsIter {
+        let idxs = match (self.first, self.bits) {
+            (0, _) => YearIdxs::Range(0..0),
+            (_, 0) => YearIdxs::Range(0..(self.last - self.first) / self.step + 1),
+            (_, bits) => YearIdxs::Bits(BitIter(bits)),
+        };
+        YearsIter {
+            first: self.first,
+            step: self.step,
+            idxs,
+        }
+    }
+}
+
+impl IntoIterator for Years {
+    type Item = Year;
+    type IntoIter = YearsIter;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for Years {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[derive(Clone)]
+//Indexes of years within set.
+enum YearIdxs {
+    Range(core::ops::Range<u16>),
+    Bits(BitIter),
+}
+
+#[derive(Clone)]
+///Iterator over [Years] in ascending order.
+pub struct YearsIter {
+    first: u16,
+    step: u16,
+    idxs: YearIdxs,
+}
+
+impl YearsIter {
+    #[inline(always)]
+    fn year(&self, idx: u16) -> Year {
+        Year::from_num_asserted(self.first + idx * self.step)
+    }
+}
+
+impl Iterator for YearsIter {
+    type Item = Year;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = match &mut self.idxs {
+            YearIdxs::Range(idxs) => idxs.next()?,
+            YearIdxs::Bits(idxs) => idxs.next()?.into(),
+        };
+        Some(self.year(idx))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.idxs {
+            YearIdxs::Range(idxs) => idxs.size_hint(),
+            YearIdxs::Bits(idxs) => idxs.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for YearsIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = match &mut self.idxs {
+            YearIdxs::Range(idxs) => idxs.next_back()?,
+            YearIdxs::Bits(idxs) => idxs.next_back()?.into(),
+        };
+        Some(self.year(idx))
+    }
+}
+
+impl ExactSizeIterator for YearsIter {
+}
+
+impl core::iter::FusedIterator for YearsIter {
+}
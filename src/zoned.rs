@@ -0,0 +1,416 @@
+//! Time zone aware schedules.
+//!
+//!Expression can be prefixed with `CRON_TZ=<zone>` or `TZ=<zone>`, where `zone` is IANA time zone name (e.g. `Europe/Berlin`).
+//!Without prefix expression is evaluated in UTC.
+//!
+//!Time zone is loaded from system time zone database.
+//!With `tz-bundled` feature, bundled copy of IANA database is used when time zone is not found in system one.
+//!
+//!Available with `tz` feature.
+//!
+//!## Usage
+//!
+//!```
+//!use cronchik::zoned::ZonedSchedule;
+//!
+//!let schedule = ZonedSchedule::parse_str("CRON_TZ=UTC 0 9 * * *").unwrap();
+//!assert_eq!(schedule.time_zone_name(), Some("UTC"));
+//!assert_eq!(format!("{}", schedule), "CRON_TZ=UTC 0 9 * * *");
+//!```
+
+use core::fmt;
+use std::string::String;
+
+use crate::{CronSchedule, ParseError, ParseOptions};
+
+const PREFIXES: [&'static str; 2] = ["CRON_TZ=", "TZ="];
+const DAY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Copy, Clone)]
+///Time zone aware cron expression parser error
+pub enum ZonedParseError {
+    ///Indicates invalid cron expression.
+    Cron(ParseError),
+    ///Time zone is not found in database.
+    UnknownTimeZone,
+}
+
+impl fmt::Display for ZonedParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cron(error) => fmt::Display::fmt(error, fmt),
+            Self::UnknownTimeZone => fmt.write_str("Unknown time zone"),
+        }
+    }
+}
+
+impl std::error::Error for ZonedParseError {}
+
+impl From<ParseError> for ZonedParseError {
+    #[inline(always)]
+    fn from(error: ParseError) -> Self {
+        Self::Cron(error)
+    }
+}
+
+//Directories of system time zone database, looked up after `TZDIR`.
+const ZONEINFO_DIRS: [&'static str; 3] = ["/usr/share/zoneinfo", "/usr/lib/zoneinfo", "/etc/zoneinfo"];
+
+///Returns whether `name` looks like IANA time zone identifier (e.g. `Europe/Berlin` or `Etc/GMT+3`).
+///
+///Identifier is relative path within database, hence absolute paths and `.`/`..` components are rejected.
+fn is_iana_name(name: &str) -> bool {
+    name.bytes().all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, b'_' | b'+' | b'-' | b'/'))
+        && name.split('/').all(|part| !part.is_empty() && part != "." && part != "..")
+}
+
+///Loads time zone by its IANA name.
+///
+///System database is preferred, falling back to bundled one with `tz-bundled` feature.
+///System database is looked up in directory specified by `TZDIR` environment variable, then in `/usr/share/zoneinfo`, `/usr/lib/zoneinfo` and `/etc/zoneinfo`.
+///File, which is not valid time zone data, is skipped.
+///
+///Only identifiers are accepted: file paths and POSIX TZ rules (e.g. `XYZ3`) are not looked up.
+pub fn load_time_zone(name: &str) -> Option<tz::TimeZone> {
+    if !is_iana_name(name) {
+        return None;
+    }
+
+    let tz_dir = std::env::var_os("TZDIR");
+    let dirs = tz_dir.iter().map(std::path::Path::new).chain(ZONEINFO_DIRS.iter().map(std::path::Path::new));
+    for dir in dirs {
+        if let Ok(data) = std::fs::read(dir.join(name)) {
+            if let Ok(zone) = tz::TimeZone::from_tz_data(&data) {
+                return Some(zone);
+            }
+        }
+    }
+
+    #[cfg(feature = "tz-bundled")]
+    if let Some(data) = tzdb::raw_tz_by_name(name) {
+        return tz::TimeZone::from_tz_data(data).ok();
+    }
+
+    None
+}
+
+///Returns UTC time of transition on `rule_day` of `year`, where `day_time` is UTC time since start of the day in seconds.
+fn rule_day_unix_time(rule_day: &tz::timezone::RuleDay, year: i32, day_time: i64) -> Option<i64> {
+    use tz::timezone::RuleDay;
+
+    let date = match rule_day {
+        //Day `1..=365`, which never refers to February 29th.
+        RuleDay::Julian1WithoutLeap(day) => {
+            let day = day.get();
+            let day = match crate::utils::is_leap_year(year) && day > 59 {
+                true => day + 1,
+                false => day,
+            };
+            time::Date::from_ordinal_date(year, day).ok()?
+        },
+        //Day `0..=365`, where the last one is January 1st of the next year in non-leap year.
+        RuleDay::Julian0WithLeap(day) => time::Date::from_ordinal_date(year, 1).ok()?.checked_add(time::Duration::days(day.get().into()))?,
+        //`n`th week day of the month, where 5th week is the last one.
+        RuleDay::MonthWeekDay(rule) => {
+            let month = rule.month();
+            let days_in_month = crate::utils::days_in_month(year, month);
+            let mut day = 1 + (7 + rule.week_day() - crate::utils::weekday(year, month, 1)) % 7 + (rule.week() - 1) * 7;
+            if day > days_in_month {
+                day -= 7;
+            }
+            time::Date::from_calendar_date(year, time::Month::January.nth_next(month - 1), day).ok()?
+        },
+    };
+
+    Some(date.midnight().assume_utc().unix_timestamp() + day_time)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes how to schedule local time, that doesn't exist due to transition forward (e.g. DST start).
+pub enum GapPolicy {
+    ///Local time is not scheduled.
+    Skip,
+    ///Local time is scheduled once at the transition, which is first valid point in time after it.
+    NextValid,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes how to schedule local time, that occurs twice due to transition backward (e.g. DST end).
+pub enum OverlapPolicy {
+    ///Local time is scheduled only at first point in time.
+    Once,
+    ///Local time is scheduled at both points in time.
+    Twice,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///Describes how to schedule local time around UTC offset transitions.
+pub struct DstPolicy {
+    gap: GapPolicy,
+    overlap: OverlapPolicy,
+}
+
+impl DstPolicy {
+    #[inline(always)]
+    ///Creates default policy.
+    ///
+    ///- Gap policy is [GapPolicy::Skip];
+    ///- Overlap policy is [OverlapPolicy::Twice].
+    pub const fn new() -> Self {
+        Self {
+            gap: GapPolicy::Skip,
+            overlap: OverlapPolicy::Twice,
+        }
+    }
+
+    #[inline(always)]
+    ///Specifies how to schedule local time, that doesn't exist.
+    pub const fn gap(mut self, gap: GapPolicy) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    #[inline(always)]
+    ///Specifies how to schedule local time, that occurs twice.
+    pub const fn overlap(mut self, overlap: OverlapPolicy) -> Self {
+        self.overlap = overlap;
+        self
+    }
+}
+
+impl Default for DstPolicy {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+///Cron schedule, evaluated in specific time zone.
+pub struct ZonedSchedule {
+    //None means UTC without prefix.
+    name: Option<String>,
+    zone: tz::TimeZone,
+    schedule: CronSchedule,
+    policy: DstPolicy,
+}
+
+impl ZonedSchedule {
+    #[inline]
+    ///Creates schedule, evaluated in `zone` with specified `name`.
+    pub fn new(schedule: CronSchedule, name: String, zone: tz::TimeZone) -> Self {
+        Self {
+            name: Some(name),
+            zone,
+            schedule,
+            policy: DstPolicy::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates schedule, evaluated in UTC.
+    pub fn utc(schedule: CronSchedule) -> Self {
+        Self {
+            name: None,
+            zone: tz::TimeZone::utc(),
+            schedule,
+            policy: DstPolicy::new(),
+        }
+    }
+
+    #[inline]
+    ///Parses cron expression with optional time zone prefix from string.
+    ///
+    ///Refer to [CronSchedule::parse_str] for syntax of expression.
+    pub fn parse_str(text: &str) -> Result<Self, ZonedParseError> {
+        Self::parse_str_with(text, ParseOptions::new())
+    }
+
+    ///Parses cron expression with optional time zone prefix from string, using provided `options`.
+    pub fn parse_str_with(text: &str, options: ParseOptions) -> Result<Self, ZonedParseError> {
+        let text = text.trim();
+
+        for prefix in PREFIXES.iter() {
+            if let Some(text) = text.strip_prefix(prefix) {
+                let (name, expr) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+                let zone = load_time_zone(name).ok_or(ZonedParseError::UnknownTimeZone)?;
+                let schedule = CronSchedule::parse_str_with(expr, options)?;
+                return Ok(Self::new(schedule, name.into(), zone));
+            }
+        }
+
+        CronSchedule::parse_str_with(text, options).map(Self::utc).map_err(Into::into)
+    }
+
+    #[inline(always)]
+    ///Returns underlying schedule.
+    pub fn schedule(&self) -> &CronSchedule {
+        &self.schedule
+    }
+
+    #[inline(always)]
+    ///Returns name of time zone, if specified.
+    pub fn time_zone_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    #[inline(always)]
+    ///Returns time zone.
+    pub fn time_zone(&self) -> &tz::TimeZone {
+        &self.zone
+    }
+
+    #[inline(always)]
+    ///Specifies how to schedule local time around UTC offset transitions.
+    pub fn with_dst_policy(mut self, policy: DstPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    #[inline(always)]
+    ///Returns how local time is scheduled around UTC offset transitions.
+    pub fn dst_policy(&self) -> DstPolicy {
+        self.policy
+    }
+
+    ///Returns UTC offset in seconds of time zone at `unix_time`.
+    fn offset_at(&self, unix_time: i64) -> Option<i32> {
+        self.zone.find_local_time_type(unix_time).ok().map(|local_time_type| local_time_type.ut_offset())
+    }
+
+    ///Returns first point in time within `(from, to]`, at which UTC offset is different from `offset`.
+    ///
+    ///Transitions are looked up in time zone's table, followed by transitions of its extra rule, that applies after the table.
+    fn next_transition(&self, from: i64, to: i64, offset: i32) -> Option<Option<i64>> {
+        let zone = self.zone.as_ref();
+        //Transitions are stored in time with leap seconds.
+        let unix_time = |transition: &tz::timezone::Transition| {
+            let unix_leap_time = transition.unix_leap_time();
+            let correction = zone.leap_seconds().iter().rev().find(|leap| leap.unix_leap_time() < unix_leap_time).map_or(0, |leap| leap.correction());
+            unix_leap_time - i64::from(correction)
+        };
+
+        let transitions = zone.transitions();
+        let next = transitions.partition_point(|transition| unix_time(transition) <= from);
+        for time in transitions[next..].iter().map(unix_time) {
+            if time > to {
+                return Some(None);
+            }
+            if self.offset_at(time)? != offset {
+                return Some(Some(time));
+            }
+        }
+
+        let rule = match zone.extra_rule() {
+            Some(tz::timezone::TransitionRule::Alternate(rule)) => rule,
+            _ => return Some(None),
+        };
+        let from = match transitions.last() {
+            Some(last) => core::cmp::max(from, unix_time(last)),
+            None => from,
+        };
+        let start_time = i64::from(rule.dst_start_time()) - i64::from(rule.std().ut_offset());
+        let end_time = i64::from(rule.dst_end_time()) - i64::from(rule.dst().ut_offset());
+
+        //Transition of previous year can be in the current one, as transition time is not limited to a day.
+        let mut year = time::OffsetDateTime::from_unix_timestamp(from).ok()?.year() - 1;
+        loop {
+            let mut times = [rule_day_unix_time(rule.dst_start(), year, start_time)?, rule_day_unix_time(rule.dst_end(), year, end_time)?];
+            times.sort_unstable();
+            if times[0] > to {
+                break Some(None);
+            }
+
+            for time in times.iter().copied().filter(|time| *time > from && *time <= to) {
+                if self.offset_at(time)? != offset {
+                    return Some(Some(time));
+                }
+            }
+            year += 1;
+        }
+    }
+
+    #[inline]
+    ///Returns next point if time, after `time`, accordingly to the schedule in its time zone.
+    ///
+    ///Returned time has offset of time zone at that point in time.
+    ///
+    ///## Panics
+    ///
+    ///If schedule has no more points in time after `time`, which is possible only when year field is specified.
+    ///Use [try_next_time_from](#method.try_next_time_from) to handle it.
+    pub fn next_time_from(&self, time: time::OffsetDateTime) -> time::OffsetDateTime {
+        self.try_next_time_from(time).expect("Schedule has no more points in time after last allowed year")
+    }
+
+    ///Returns next point if time, after `time`, accordingly to the schedule in its time zone.
+    ///
+    ///Calendar calculations are performed on local time of time zone.
+    ///Local time, that doesn't exist or occurs twice due to UTC offset transition (e.g. DST), is scheduled accordingly to [DstPolicy].
+    ///
+    ///Returns `None` if last year allowed by schedule has passed.
+    pub fn try_next_time_from(&self, time: time::OffsetDateTime) -> Option<time::OffsetDateTime> {
+        let mut from = time;
+        let mut offset = self.offset_at(time.unix_timestamp())?;
+
+        //When `time` is within repeated local time, it should not be scheduled again.
+        if self.policy.overlap == OverlapPolicy::Once {
+            let time = time.unix_timestamp();
+            let start = time - DAY;
+            if let Some(transition) = self.next_transition(start, time, self.offset_at(start)?)? {
+                let shift = i64::from(offset - self.offset_at(transition - 1)?);
+                if shift < 0 && time < transition - shift {
+                    from = time::OffsetDateTime::from_unix_timestamp(transition - shift - 1).ok()?;
+                }
+            }
+        }
+
+        //UTC offset is constant between transitions, so schedule is calculated with fixed offset until it crosses transition.
+        loop {
+            let utc_offset = time::UtcOffset::from_whole_seconds(offset).ok()?;
+            let next = self.schedule.try_next_time_from(from.to_offset(utc_offset))?;
+
+            let transition = match self.next_transition(from.unix_timestamp(), next.unix_timestamp(), offset)? {
+                Some(transition) => transition,
+                None => break Some(next),
+            };
+            let new_offset = self.offset_at(transition)?;
+            let shift = i64::from(new_offset - offset);
+            offset = new_offset;
+
+            //`next` is local time with previous offset, so it is within gap if it is before local time at transition.
+            from = match (self.policy.gap, self.policy.overlap) {
+                (GapPolicy::NextValid, _) if shift > 0 && next.unix_timestamp() < transition + shift => {
+                    let utc_offset = time::UtcOffset::from_whole_seconds(offset).ok()?;
+                    break time::OffsetDateTime::from_unix_timestamp(transition).ok().map(|next| next.to_offset(utc_offset));
+                },
+                //Continue after repeated local time.
+                (_, OverlapPolicy::Once) if shift < 0 => time::OffsetDateTime::from_unix_timestamp(transition - shift - 1).ok()?,
+                _ => time::OffsetDateTime::from_unix_timestamp(transition - 1).ok()?,
+            };
+        }
+    }
+
+    #[inline(always)]
+    ///Returns next point if time, after current time.
+    pub fn next_time_from_now(&self) -> time::OffsetDateTime {
+        self.next_time_from(time::OffsetDateTime::now_utc())
+    }
+}
+
+impl fmt::Debug for ZonedSchedule {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+impl fmt::Display for ZonedSchedule {
+    ///Writes cron expression, prefixed with `CRON_TZ=<zone>` when time zone is specified.
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name.as_ref() {
+            fmt.write_fmt(format_args!("{}{} ", PREFIXES[0], name))?;
+        }
+        fmt::Display::fmt(&self.schedule, fmt)
+    }
+}
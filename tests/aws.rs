@@ -0,0 +1,126 @@
+use cronchik::aws::{AwsParseError, AwsSchedule, Rate, RateUnit};
+use cronchik::{CronSchedule, Day};
+
+#[test]
+fn verify_aws_cron_parser() {
+    let crons = [
+        ("cron(0 10 * * ? *)", "0 10 * * *", "cron(0 10 * * ? *)"),
+        ("cron(15 12 * * ? *)", "15 12 * * *", "cron(15 12 * * ? *)"),
+        ("cron(0 18 ? * MON-FRI *)", "0 18 * * MON-FRI", "cron(0 18 ? * MON-FRI *)"),
+        ("cron(0 8 1 * ? *)", "0 8 1 * *", "cron(0 8 1 * ? *)"),
+        ("cron(0/10 * ? * MON-FRI *)", "*/10 * * * 1-5", "cron(0,10,20,30,40,50 * ? * MON-FRI *)"),
+        ("cron(0/5 8-17 ? * 2-6 *)", "*/5 8-17 * * MON-FRI", "cron(0,5,10,15,20,25,30,35,40,45,50,55 8-17 ? * MON-FRI *)"),
+        ("cron(0 9 ? * 2#1 *)", "0 9 * * MON#1", "cron(0 9 ? * MON#1 *)"),
+        ("cron(0 0 L * ? *)", "0 0 L * *", "cron(0 0 L * ? *)"),
+        ("cron(0 0 ? * L *)", "0 0 * * SAT", "cron(0 0 ? * SAT *)"),
+        ("cron(0 9 15W * ? 2026)", "0 0 9 15W * * 2026", "cron(0 9 15W * ? 2026)"),
+    ];
+
+    for (cron, expected, expected_text) in crons.iter() {
+        let schedule = AwsSchedule::parse_str(cron).unwrap();
+        assert_eq!(schedule, AwsSchedule::Cron(CronSchedule::parse_str(expected).unwrap()));
+        let text = format!("{}", schedule);
+        assert_eq!(text, *expected_text);
+        assert_eq!(schedule, AwsSchedule::parse_str(&text).unwrap());
+    }
+
+    match AwsSchedule::parse_str("cron(0 10 * * ? *)").unwrap() {
+        AwsSchedule::Cron(schedule) => assert_eq!(schedule.days_of_week().len(), 7),
+        AwsSchedule::Rate(_) => unreachable!(),
+    }
+    match AwsSchedule::parse_str("cron(0 10 ? * 1 *)").unwrap() {
+        AwsSchedule::Cron(schedule) => assert_eq!(schedule.days_of_week().days().iter().collect::<Vec<_>>(), [Day::Sunday]),
+        AwsSchedule::Rate(_) => unreachable!(),
+    }
+    //AWS allows years up to 2199
+    match AwsSchedule::parse_str("cron(0 0 1 1 ? 2150)").unwrap() {
+        AwsSchedule::Cron(schedule) => assert_eq!(schedule.years().unwrap().iter().map(|year| year.into()).collect::<Vec<u16>>(), [2150]),
+        AwsSchedule::Rate(_) => unreachable!(),
+    }
+    assert!(AwsSchedule::parse_str("cron(0 0 1 1 ? 2100-2199)").is_ok());
+
+    assert!(matches!(AwsSchedule::parse_str("0 10 * * ? *"), Err(AwsParseError::InvalidWrapper)));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 * * ? *"), Err(AwsParseError::InvalidWrapper)));
+    assert!(matches!(AwsSchedule::parse_str("CRON(0 10 * * ? *)"), Err(AwsParseError::InvalidWrapper)));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 * * *)"), Err(AwsParseError::Cron(_))));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 0 10 * * ? *)"), Err(AwsParseError::Cron(_))));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 * * 0 *)"), Err(AwsParseError::Cron(_))));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 * * * *)"), Err(AwsParseError::InvalidDays)));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 ? * ? *)"), Err(AwsParseError::InvalidDays)));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 10 1 * MON *)"), Err(AwsParseError::InvalidDays)));
+    assert!(matches!(AwsSchedule::parse_str("cron(0 0 1 1 ? 2200)"), Err(AwsParseError::Cron(_))));
+
+    //Both day fields restricted are written as is, but cannot be parsed back.
+    let schedule = AwsSchedule::Cron(CronSchedule::parse_str("0 0 1 * MON").unwrap());
+    let text = format!("{}", schedule);
+    assert_eq!(text, "cron(0 0 1 * MON *)");
+    assert!(matches!(AwsSchedule::parse_str(&text), Err(AwsParseError::InvalidDays)));
+}
+
+#[test]
+fn verify_aws_rate_parser() {
+    let rates = [
+        ("rate(1 minute)", 1, RateUnit::Minute),
+        ("rate(5 minutes)", 5, RateUnit::Minute),
+        ("rate(1 hour)", 1, RateUnit::Hour),
+        (" rate(12 hours) ", 12, RateUnit::Hour),
+        ("rate(1 day)", 1, RateUnit::Day),
+        ("rate(7 days)", 7, RateUnit::Day),
+    ];
+
+    for (text, value, unit) in rates.iter() {
+        let schedule = AwsSchedule::parse_str(text).unwrap();
+        assert_eq!(schedule, AwsSchedule::Rate(Rate::new(*value, *unit).unwrap()));
+        assert_eq!(format!("{}", schedule), text.trim());
+    }
+
+    assert!(Rate::new(0, RateUnit::Day).is_none());
+    assert!(matches!(AwsSchedule::parse_str("rate(0 minutes)"), Err(AwsParseError::InvalidRateValue)));
+    assert!(matches!(AwsSchedule::parse_str("rate(-1 minutes)"), Err(AwsParseError::InvalidRateValue)));
+    assert!(matches!(AwsSchedule::parse_str("rate(minutes)"), Err(AwsParseError::InvalidRateValue)));
+    assert!(matches!(AwsSchedule::parse_str("rate(1 minutes)"), Err(AwsParseError::InvalidRateUnit)));
+    assert!(matches!(AwsSchedule::parse_str("rate(5 minute)"), Err(AwsParseError::InvalidRateUnit)));
+    assert!(matches!(AwsSchedule::parse_str("rate(5 seconds)"), Err(AwsParseError::InvalidRateUnit)));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn should_schedule_aws_cron_in_utc() {
+    let start = time::macros::datetime!(2026-03-02 10:00 +02:00);
+    let schedule = AwsSchedule::parse_str("cron(0 9 ? * MON-FRI *)").unwrap();
+
+    //Start is 08:00 in UTC.
+    let next = schedule.next_time_from(start, start).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 09:00 UTC));
+    assert_eq!(next.offset(), time::UtcOffset::UTC);
+
+    let next = schedule.next_time_from(start, next).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-03 09:00 UTC));
+
+    //Schedule doesn't run before it is created.
+    let next = schedule.next_time_from(start, start - time::Duration::days(7)).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 09:00 UTC));
+
+    let schedule = AwsSchedule::parse_str("cron(0 9 ? * MON-FRI 2026)").unwrap();
+    assert!(schedule.next_time_from(start, time::macros::datetime!(2026-12-31 09:00 UTC)).is_none());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn should_schedule_aws_rate_from_start() {
+    let start = time::macros::datetime!(2026-03-02 10:00:30 UTC);
+    let schedule = AwsSchedule::parse_str("rate(5 minutes)").unwrap();
+
+    let next = schedule.next_time_from(start, start).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 10:05:30 UTC));
+    let next = schedule.next_time_from(start, next).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 10:10:30 UTC));
+    let next = schedule.next_time_from(start, next - time::Duration::milliseconds(1)).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 10:10:30 UTC));
+    let next = schedule.next_time_from(start, start - time::Duration::days(1)).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-02 10:05:30 UTC));
+
+    let schedule = AwsSchedule::parse_str("rate(1 day)").unwrap();
+    let next = schedule.next_time_from(start, time::macros::datetime!(2026-03-12 12:00 UTC)).unwrap();
+    assert_eq!(next, time::macros::datetime!(2026-03-13 10:00:30 UTC));
+}
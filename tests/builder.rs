@@ -0,0 +1,63 @@
+use cronchik::{BuildError, CronSchedule, Day, DayMatching, Hour, Month, Year};
+
+fn parse(expr: &str) -> CronSchedule {
+    CronSchedule::parse_str(expr).unwrap()
+}
+
+#[test]
+fn should_build_default_schedule() {
+    assert_eq!(CronSchedule::builder().build().unwrap(), parse("* * * * *"));
+}
+
+#[test]
+fn should_build_from_ranges_and_steps() {
+    let schedule = CronSchedule::builder().seconds([30])
+                                          .minutes((0..60).step_by(15))
+                                          .hours(9..=17)
+                                          .days_of_month(1..8)
+                                          .months((1..=12).step_by(3))
+                                          .days_of_week(1..=5)
+                                          .years(2024..=2030)
+                                          .day_matching(DayMatching::And)
+                                          .build()
+                                          .unwrap();
+    let expected = CronSchedule::parse_str_with("30 */15 9-17 1-7 */3 1-5 2024-2030", cronchik::ParseOptions::new().day_matching(DayMatching::And)).unwrap();
+    assert_eq!(schedule, expected);
+}
+
+#[test]
+fn should_build_from_typed_values() {
+    let schedule = CronSchedule::builder().hours(Hour::from_num(12))
+                                          .months([Month::January, Month::July])
+                                          .days_of_week([Day::Monday, Day::Friday])
+                                          .years(Year::from_num(2025))
+                                          .build()
+                                          .unwrap();
+    assert_eq!(schedule, parse("0 * 12 * JAN,JUL MON,FRI 2025"));
+}
+
+#[test]
+fn should_build_unrestricted_years() {
+    let schedule = CronSchedule::builder().years(Year::MIN..=Year::MAX).build().unwrap();
+    assert!(schedule.years().is_none());
+    assert_eq!(schedule, parse("* * * * *"));
+}
+
+#[test]
+fn should_reject_invalid_fields() {
+    assert!(matches!(CronSchedule::builder().minutes(0..0).build(), Err(BuildError::EmptyField("Minute"))));
+    assert!(matches!(CronSchedule::builder().hours(20..=24).build(), Err(BuildError::OutOfRange("Hour", 24))));
+    assert!(matches!(CronSchedule::builder().days_of_week([7]).build(), Err(BuildError::OutOfRange("Day of Week", 7))));
+    assert!(matches!(CronSchedule::builder().years(2190..2300).build(), Err(BuildError::OutOfRange("Year", 2200))));
+    assert!(matches!(CronSchedule::builder().years(Year::from_num(3000)).build(), Err(BuildError::EmptyField("Year"))));
+    assert!(matches!(CronSchedule::builder().years([2000, 2001, 2100]).build(), Err(BuildError::SparseYears)));
+    //First error is reported.
+    assert!(matches!(CronSchedule::builder().seconds([60]).hours(0..0).build(), Err(BuildError::OutOfRange("Second", 60))));
+}
+
+#[test]
+fn should_reject_impossible_date() {
+    let result = CronSchedule::builder().days_of_month([30, 31]).months([Month::February]).build();
+    assert!(matches!(result, Err(BuildError::ImpossibleDate)));
+    assert_eq!(result.unwrap_err().to_string(), "Schedule never matches any date");
+}
@@ -0,0 +1,96 @@
+use cronchik::{CronSchedule, Names};
+use proptest::prelude::*;
+
+fn canonical(expr: &str, names: Names) -> String {
+    CronSchedule::parse_str(expr).unwrap().display_canonical(names).to_string()
+}
+
+#[test]
+fn should_write_steps() {
+    assert_eq!(canonical("0,15,30,45 * * * *", Names::Numeric), "*/15 * * * *");
+    assert_eq!(canonical("5,20,35,50 * * * *", Names::Numeric), "5/15 * * * *");
+    assert_eq!(canonical("10,20,30,40 * * * *", Names::Numeric), "10-40/10 * * * *");
+    assert_eq!(canonical("0 1,3,5,7,9,11,13,15,17,19,21,23 * * *", Names::Numeric), "0 1/2 * * *");
+    assert_eq!(canonical("0 0 1-31/2 * *", Names::Numeric), "0 0 */2 * *");
+    assert_eq!(canonical("*/20 * * * * *", Names::Numeric), "*/20 * * * * *");
+    assert_eq!(canonical("0 0 0 1 1 * 2000-2199/25", Names::Numeric), "0 0 0 1 1 * 2000/25");
+}
+
+#[test]
+fn should_prefer_list_of_the_same_length() {
+    assert_eq!(canonical("0,30 * * * *", Names::Numeric), "0,30 * * * *");
+    assert_eq!(canonical("*/30 * * * *", Names::Numeric), "0,30 * * * *");
+    assert_eq!(canonical("10-30/10 * * * *", Names::Numeric), "10,20,30 * * * *");
+    assert_eq!(canonical("1-5 * * * *", Names::Numeric), "1-5 * * * *");
+    assert_eq!(canonical("0-59 0-23 1-31 1-12 0-6", Names::Numeric), "* * * * *");
+}
+
+#[test]
+fn should_write_names() {
+    assert_eq!(canonical("0 0 * 1,4,7,10 MON,TUE,WED,THU,FRI", Names::Textual), "0 0 * */3 MON-FRI");
+    assert_eq!(canonical("0 0 * 1,4,7,10 MON,TUE,WED,THU,FRI", Names::Numeric), "0 0 * */3 1-5");
+    assert_eq!(canonical("0 0 * JAN,JUL SUN,SAT", Names::Textual), "0 0 * */6 */6");
+    assert_eq!(canonical("0 0 * jan,jul 0,6", Names::Numeric), "0 0 * 1,7 0,6");
+    assert_eq!(canonical("0 12 * * FRI#5,SUNL", Names::Numeric), "0 12 * * 5#5,0L");
+    assert_eq!(canonical("0 12 * * 5#5,0L", Names::Textual), "0 12 * * FRI#5,SUNL");
+}
+
+#[test]
+fn should_write_special_days() {
+    assert_eq!(canonical("0 0 L-3,15W * *", Names::Numeric), "0 0 L-3,15W * *");
+    assert_eq!(canonical("0 0 1,8,15,22,L * *", Names::Numeric), "0 0 1-22/7,L * *");
+    assert_eq!(canonical("0 0 1-31,LW * *", Names::Numeric), "0 0 1-31,LW * *");
+    assert_eq!(canonical("0 0 * * 1,3,5,MON#1", Names::Textual), "0 0 * * MON/2,MON#1");
+}
+
+#[test]
+fn should_write_nickname_with_alternate_flag() {
+    let schedule = CronSchedule::parse_str("0 0 * * 0").unwrap();
+    assert_eq!(format!("{:#}", schedule.display_canonical(Names::Textual)), "@weekly");
+    assert_eq!(format!("{}", schedule.display_canonical(Names::Textual)), "0 0 * * SUN");
+}
+
+fn field(min: u8, max: u8) -> impl Strategy<Value = String> {
+    proptest::collection::btree_set(min..=max, 1..8).prop_map(|values| {
+        values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",")
+    })
+}
+
+fn step(min: u8, max: u8) -> impl Strategy<Value = String> {
+    (min..=max, min..=max, 1..=max).prop_map(|(first, last, step)| {
+        format!("{}-{}/{}", core::cmp::min(first, last), core::cmp::max(first, last), step)
+    })
+}
+
+fn years() -> impl Strategy<Value = String> {
+    (2000u16..2100, 2000u16..2100, 1u16..100).prop_map(|(first, last, step)| {
+        format!(" {}-{}/{}", core::cmp::min(first, last), core::cmp::max(first, last), step)
+    })
+}
+
+fn expr(min: u8, max: u8) -> impl Strategy<Value = String> {
+    prop_oneof![Just("*".to_owned()), field(min, max), step(min, max)]
+}
+
+proptest! {
+    #[test]
+    fn canonical_expression_is_equivalent(
+        second in expr(0, 59),
+        minute in expr(0, 59),
+        hour in expr(0, 23),
+        day_m in prop_oneof![expr(1, 28), Just("L-2,15W".to_owned()), Just("1-7/3,LW".to_owned())],
+        month in expr(1, 12),
+        day_w in prop_oneof![expr(0, 6), Just("1-5/2,FRI#2".to_owned()), Just("SATL".to_owned())],
+        year in prop_oneof![Just(String::new()), years()],
+        names in prop_oneof![Just(Names::Numeric), Just(Names::Textual)],
+    ) {
+        let expr = format!("{} {} {} {} {} {}{}", second, minute, hour, day_m, month, day_w, year);
+        let schedule = CronSchedule::parse_str(&expr).unwrap();
+        let canonical = schedule.display_canonical(names).to_string();
+
+        let parsed = CronSchedule::parse_str(&canonical).unwrap();
+        prop_assert_eq!(&parsed, &schedule, "{} is written as {}", expr, canonical);
+        prop_assert_eq!(parsed.display_canonical(names).to_string(), canonical.clone());
+        prop_assert!(canonical.len() <= schedule.to_string().len(), "{} is longer than {}", canonical, schedule);
+    }
+}
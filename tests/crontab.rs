@@ -0,0 +1,113 @@
+use cronchik::crontab::{CrontabErrorKind, CrontabFormat, CrontabParser, Line};
+use cronchik::{CronSchedule, ParseError};
+
+const USER_CRONTAB: &str = "# m h dom mon dow command
+SHELL=/bin/bash
+MAILTO = \"ops@example.com\"
+  PATH='/usr/local/bin:/usr/bin'
+
+*/15 9-17 * * MON-FRI  /usr/bin/check --quiet
+@daily /usr/bin/backup > /dev/null 2>&1
+\t#indented comment\r
+";
+
+#[test]
+fn verify_crontab_parser() {
+    let lines = CrontabParser::new(USER_CRONTAB).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(lines.len(), 8);
+
+    assert_eq!(lines[0], Line::Comment(" m h dom mon dow command"));
+    assert_eq!(lines[1], Line::Env("SHELL", "/bin/bash"));
+    assert_eq!(lines[2], Line::Env("MAILTO", "ops@example.com"));
+    assert_eq!(lines[3], Line::Env("PATH", "/usr/local/bin:/usr/bin"));
+    assert_eq!(lines[4], Line::Blank);
+    match &lines[5] {
+        Line::Entry(entry) => {
+            assert_eq!(*entry.schedule(), CronSchedule::parse_str("*/15 9-17 * * MON-FRI").unwrap());
+            assert_eq!(entry.user(), None);
+            assert_eq!(entry.command(), "/usr/bin/check --quiet");
+        },
+        line => panic!("Unexpected line: {:?}", line),
+    }
+    match &lines[6] {
+        Line::Entry(entry) => {
+            assert_eq!(*entry.schedule(), CronSchedule::parse_str("@daily").unwrap());
+            assert_eq!(entry.command(), "/usr/bin/backup > /dev/null 2>&1");
+        },
+        line => panic!("Unexpected line: {:?}", line),
+    }
+    assert_eq!(lines[7], Line::Comment("indented comment"));
+}
+
+#[test]
+fn verify_system_crontab_parser() {
+    let text = "17 * * * * root cd / && run-parts --report /etc/cron.hourly\n@weekly  nobody   /usr/bin/cleanup\n";
+    let lines = CrontabParser::with_format(text, CrontabFormat::System).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(lines.len(), 2);
+
+    match &lines[0] {
+        Line::Entry(entry) => {
+            assert_eq!(*entry.schedule(), CronSchedule::parse_str("17 * * * *").unwrap());
+            assert_eq!(entry.user(), Some("root"));
+            assert_eq!(entry.command(), "cd / && run-parts --report /etc/cron.hourly");
+        },
+        line => panic!("Unexpected line: {:?}", line),
+    }
+    match &lines[1] {
+        Line::Entry(entry) => {
+            assert_eq!(*entry.schedule(), CronSchedule::parse_str("@weekly").unwrap());
+            assert_eq!(entry.user(), Some("nobody"));
+            assert_eq!(entry.command(), "/usr/bin/cleanup");
+        },
+        line => panic!("Unexpected line: {:?}", line),
+    }
+
+    let error = CrontabParser::with_format("0 0 * * * root", CrontabFormat::System).next().unwrap().unwrap_err();
+    assert!(matches!(error.kind(), CrontabErrorKind::MissingCommand));
+    let error = CrontabParser::with_format("0 0 * * *  ", CrontabFormat::System).next().unwrap().unwrap_err();
+    assert!(matches!(error.kind(), CrontabErrorKind::MissingUser));
+}
+
+#[test]
+fn verify_crontab_parser_errors() {
+    let text = "# header\nA=1\n0 0 * * * ok\n  0 25 * * * /bin/true\n";
+    let error = CrontabParser::new(text).find_map(Result::err).unwrap();
+    assert_eq!(error.line(), 4);
+    assert_eq!(error.column(), 5);
+    assert!(matches!(error.kind(), CrontabErrorKind::Cron(ParseError::InvalidExpr("Hour", _))));
+    assert_eq!(format!("{}", error), "4:5: Hour: InvalidEntryRange");
+
+    let error = CrontabParser::new("0 0 * * MOO cmd").next().unwrap().unwrap_err();
+    assert_eq!(error.column(), 9);
+    assert!(matches!(error.kind(), CrontabErrorKind::Cron(ParseError::InvalidExpr("Day of Week", _))));
+
+    let error = CrontabParser::new("0 0 * *").next().unwrap().unwrap_err();
+    assert_eq!(error.column(), 1);
+    assert!(matches!(error.kind(), CrontabErrorKind::Cron(ParseError::Incomplete)));
+
+    let error = CrontabParser::new("0 0 * * *").next().unwrap().unwrap_err();
+    assert_eq!(error.column(), 10);
+    assert!(matches!(error.kind(), CrontabErrorKind::MissingCommand));
+
+    let error = CrontabParser::new("@reboot /bin/true").next().unwrap().unwrap_err();
+    assert!(matches!(error.kind(), CrontabErrorKind::Cron(ParseError::Unsupported)));
+
+    let error = CrontabParser::new("\n\nNAME = \"value").nth(2).unwrap().unwrap_err();
+    assert_eq!(error.line(), 3);
+    assert_eq!(error.column(), 8);
+    assert!(matches!(error.kind(), CrontabErrorKind::UnterminatedQuote));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn verify_crontab_collection() {
+    use cronchik::crontab::Crontab;
+
+    let crontab = Crontab::parse_str(USER_CRONTAB).unwrap();
+    assert_eq!(crontab.lines().len(), 8);
+    assert_eq!(crontab.entries().count(), 2);
+    assert_eq!(crontab.env().collect::<Vec<_>>(), [("SHELL", "/bin/bash"), ("MAILTO", "ops@example.com"), ("PATH", "/usr/local/bin:/usr/bin")]);
+
+    let error = Crontab::parse_str("0 0 * * * a\n0 0 * * *\n").unwrap_err();
+    assert_eq!(error.line(), 2);
+}
@@ -0,0 +1,66 @@
+use cronchik::{CronSchedule, DayMatching, ParseOptions};
+
+fn describe(expr: &str) -> String {
+    CronSchedule::parse_str(expr).unwrap().describe().to_string()
+}
+
+#[test]
+fn should_describe_time_of_day() {
+    assert_eq!(describe("* * * * *"), "Every minute");
+    assert_eq!(describe("* * * * * *"), "Every second");
+    assert_eq!(describe("0 * * * *"), "Every hour");
+    assert_eq!(describe("1 * * * *"), "At 1 minute past the hour");
+    assert_eq!(describe("15,45 * * * *"), "At 15 and 45 minutes past the hour");
+    assert_eq!(describe("30 * * * * *"), "At 30 seconds past the minute");
+    assert_eq!(describe("30 4 * * *"), "At 04:30");
+    assert_eq!(describe("15 30 4 * * *"), "At 04:30:15");
+    assert_eq!(describe("0 1,2,5 * * *"), "At 01:00, 02:00 and 05:00");
+    assert_eq!(describe("5 9-17 * * *"), "At 5 minutes past the hour, between 09:00 and 17:59");
+    assert_eq!(describe("* 9,17 * * *"), "Every minute, between 09:00 and 09:59 and between 17:00 and 17:59");
+}
+
+#[test]
+fn should_describe_steps() {
+    assert_eq!(describe("*/5 * * * *"), "Every 5 minutes");
+    assert_eq!(describe("*/7 * * * *"), "Every 7 minutes");
+    assert_eq!(describe("5/15 * * * *"), "Every 15 minutes, starting at 5 minutes past the hour");
+    assert_eq!(describe("0-30/10 * * * *"), "Every 10 minutes, minutes 0 through 30 past the hour");
+    assert_eq!(describe("*/10 5 * * * *"), "Every 10 seconds, at 5 minutes past the hour");
+    assert_eq!(describe("*/15 9-17 * * MON-FRI"), "Every 15 minutes, between 09:00 and 17:59, Monday through Friday");
+    assert_eq!(describe("0 */6 * * *"), "Every 6 hours");
+    assert_eq!(describe("0 8-18/2 * * *"), "Every 2 hours, between 08:00 and 18:00");
+    assert_eq!(describe("30 8-18/2 * * *"), "At 30 minutes past the hour, every 2 hours, between 08:00 and 18:59");
+    assert_eq!(describe("0 0 */10 * *"), "At 00:00, every 10th day of the month, from day 1 through 31");
+    assert_eq!(describe("0 0 */2 * *"), "At 00:00, every 2nd day of the month, from day 1 through 31");
+    assert_eq!(describe("0 0 5-25/3 * *"), "At 00:00, every 3rd day of the month, from day 5 through 23");
+    assert_eq!(describe("0 0 10/7 * *"), "At 00:00, every 7th day of the month, from day 10 through 31");
+    assert_eq!(describe("0 0 1/21 * *"), "At 00:00, on days 1 and 22 of the month");
+    assert_eq!(describe("0 0 * */3 *"), "At 00:00, every 3 months");
+    assert_eq!(describe("0 0 0 1 1 * 2026/20"), "At 00:00, on day 1 of the month, only in January, every 20 years, 2026 through 2186");
+}
+
+#[test]
+fn should_describe_days() {
+    assert_eq!(describe("5 9-17 * * 1-5"), "At 5 minutes past the hour, between 09:00 and 17:59, Monday through Friday");
+    assert_eq!(describe("0 0 1 * *"), "At 00:00, on day 1 of the month");
+    assert_eq!(describe("0 0 1,15 * *"), "At 00:00, on days 1 and 15 of the month");
+    assert_eq!(describe("0 0 1-7,20 * *"), "At 00:00, on days 1 through 7 and 20 of the month");
+    assert_eq!(describe("0 0 L * *"), "At 00:00, on the last day of the month");
+    assert_eq!(describe("0 0 L-2,15W,LW * *"), "At 00:00, on 2 days before the last day, the weekday nearest day 15 and the last weekday of the month");
+    assert_eq!(describe("0 0 * * SUN"), "At 00:00, only on Sunday");
+    assert_eq!(describe("0 0 * * 1,3,5"), "At 00:00, on Monday, Wednesday and Friday");
+    assert_eq!(describe("0 0 * * MON#2,FRIL"), "At 00:00, on the second Monday of the month and the last Friday of the month");
+    assert_eq!(describe("0 0 1 * MON"), "At 00:00, on day 1 of the month, or on Monday");
+
+    let schedule = CronSchedule::parse_str_with("0 0 13 * FRI", ParseOptions::new().day_matching(DayMatching::And)).unwrap();
+    assert_eq!(schedule.describe().to_string(), "At 00:00, on day 13 of the month, only on Friday");
+}
+
+#[test]
+fn should_describe_months_and_years() {
+    assert_eq!(describe("@yearly"), "At 00:00, on day 1 of the month, only in January");
+    assert_eq!(describe("0 0 * 1-3 *"), "At 00:00, January through March");
+    assert_eq!(describe("0 0 * 1,4,7,11 *"), "At 00:00, in January, April, July and November");
+    assert_eq!(describe("0 0 0 1 1 * 2027"), "At 00:00, on day 1 of the month, only in January, only in 2027");
+    assert_eq!(describe("0 0 0 * * * 2020,2022-2024"), "At 00:00, in 2020 and 2022 through 2024");
+}
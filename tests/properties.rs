@@ -0,0 +1,81 @@
+#![cfg(feature = "time")]
+
+use cronchik::CronSchedule;
+use proptest::prelude::*;
+
+const EXPRESSIONS: [&str; 10] = [
+    "* * * * *",
+    "*/7 * * * * *",
+    "*/15 9-17 * * MON-FRI",
+    "0 0 1 * *",
+    "0 0 L-3,15W * *",
+    "30 4 1,15 * 5",
+    "0 12 * * FRI#5,SUNL",
+    "0 0 29 2 *",
+    "0 30 6 LW 1,7 *",
+    "0 0 0 13 * FRI 2020-2030,2050",
+];
+
+//Between 1980 and 2090.
+fn timestamp() -> impl Strategy<Value = i64> {
+    315_532_800i64..3_802_291_200
+}
+
+proptest! {
+    #[test]
+    fn prev_time_is_last_before(expr in proptest::sample::select(&EXPRESSIONS[..]), timestamp in timestamp(), nanos in 0u32..2) {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let time = time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap().replace_nanosecond(nanos * 500_000_000).unwrap();
+
+        if let Some(prev) = schedule.try_prev_time_from(time) {
+            prop_assert!(prev < time);
+            //`prev` may be the last point of schedule
+            if let Some(next) = schedule.try_next_time_from(prev) {
+                prop_assert!(next > prev);
+                prop_assert!(next >= time, "{} is scheduled between {} and {}", next, prev, time);
+            }
+        }
+    }
+
+    #[test]
+    fn next_time_is_first_after(expr in proptest::sample::select(&EXPRESSIONS[..]), timestamp in timestamp(), nanos in 0u32..2) {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let time = time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap().replace_nanosecond(nanos * 500_000_000).unwrap();
+
+        if let Some(next) = schedule.try_next_time_from(time) {
+            prop_assert!(next > time);
+            //`next` may be the first point of schedule
+            if let Some(prev) = schedule.try_prev_time_from(next) {
+                prop_assert!(prev < next);
+                prop_assert!(prev <= time, "{} is scheduled between {} and {}", prev, time, next);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_points_of_schedule(expr in proptest::sample::select(&EXPRESSIONS[..]), timestamp in timestamp()) {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let time = time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap();
+
+        let is_next = schedule.try_next_time_from(time - time::Duration::seconds(1)) == Some(time);
+        prop_assert_eq!(schedule.matches(time), is_next);
+        if let Some(next) = schedule.try_next_time_from(time) {
+            prop_assert!(schedule.matches(next));
+        }
+    }
+
+    #[test]
+    fn count_is_number_of_occurrences(expr in proptest::sample::select(&EXPRESSIONS[..]), timestamp in timestamp(), len in 0i64..2 * 86_400_000, offset in -12i8..14) {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let start = time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap().replace_nanosecond(500_000_000).unwrap();
+        let end = start + time::Duration::milliseconds(len);
+        let end = end.to_offset(time::UtcOffset::from_hms(offset, 0, 0).unwrap());
+
+        let mut count = 0;
+        for next in schedule.occurrences_between(start, end) {
+            prop_assert!(next >= start && next < end);
+            count += 1;
+        }
+        prop_assert_eq!(schedule.count_between(start, end), count);
+    }
+}
@@ -15,3 +15,29 @@ fn verify_serialization() {
     let reverse: CronSchedule = serde_json::from_str(&result).unwrap();
     assert_eq!(reverse, schedule);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn verify_serialization_keeps_day_matching() {
+    use cronchik::{CronSchedule, DayMatching, ParseOptions};
+
+    let options = ParseOptions::new().day_matching(DayMatching::And);
+    let schedule = CronSchedule::parse_str_with("0 0 1 * MON", options).unwrap();
+
+    let result = serde_json::to_string(&schedule).unwrap();
+    assert_eq!(result, "\"@and 0 0 1 * MON\"");
+    let reverse: CronSchedule = serde_json::from_str(&result).unwrap();
+    assert_eq!(reverse.day_matching(), DayMatching::And);
+    assert_eq!(reverse, schedule);
+
+    let schedule = CronSchedule::parse_str_with("@daily", options).unwrap();
+    let result = serde_json::to_string(&schedule).unwrap();
+    let reverse: CronSchedule = serde_json::from_str(&result).unwrap();
+    assert_eq!(reverse, schedule);
+
+    let schedule = CronSchedule::parse_str("0 0 1 * MON").unwrap();
+    let result = serde_json::to_string(&schedule).unwrap();
+    assert_eq!(result, "\"0 0 1 * MON\"");
+    let reverse: CronSchedule = serde_json::from_str(&result).unwrap();
+    assert_eq!(reverse.day_matching(), DayMatching::Or);
+}
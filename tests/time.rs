@@ -269,3 +269,474 @@ fn should_pass_100_iterations() {
 
     assert_eq!(time, expected_time);
 }
+
+#[test]
+fn should_schedule_on_seconds() {
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("*/15 * * * * *").unwrap();
+
+    assert_eq!(schedule.seconds().len(), 4);
+
+    let mut prev = time;
+    for idx in 1..=10 {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next - time, time::Duration::seconds(15 * idx));
+        prev = next;
+    }
+
+    let time = time + time::Duration::milliseconds(14_500);
+    assert_eq!(schedule.next_time_from(time).time(), time::macros::time!(00:00:15));
+}
+
+#[test]
+fn should_schedule_on_seconds_overflow() {
+    let time = time::macros::date!(2019-01-01).with_hms(23, 59, 31).unwrap().assume_utc();
+    let schedule = CronSchedule::parse_str("10,30 * * * * *").unwrap();
+
+    assert_eq!(schedule.next_time_from(time).time(), time::macros::time!(00:00:10));
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-02));
+
+    let schedule = CronSchedule::parse_str("10 5 1 * * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).time(), time::macros::time!(01:05:10));
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-02));
+}
+
+#[test]
+fn should_schedule_on_year() {
+    let time = time::macros::date!(2019-01-31).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2027").unwrap();
+
+    let next = schedule.next_time_from(time);
+    assert_eq!(next.date(), time::macros::date!(2027-01-01));
+    assert_eq!(next.time(), time::macros::time!(00:00));
+    assert_eq!(schedule.try_next_time_from(next), None);
+
+    let schedule = CronSchedule::parse_str("0 30 12 * * MON 2026,2028,2030").unwrap();
+    let next = schedule.next_time_from(time);
+    assert_eq!(next.date(), time::macros::date!(2026-01-05));
+    assert_eq!(next.time(), time::macros::time!(12:30));
+
+    let time = time::macros::date!(2026-12-31).midnight().assume_utc();
+    let next = schedule.next_time_from(time);
+    assert_eq!(next.date(), time::macros::date!(2028-01-03));
+
+    let time = time::macros::date!(2030-12-30).with_hms(12, 30, 0).unwrap().assume_utc();
+    assert_eq!(schedule.try_next_time_from(time), None);
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2020,2021,2050,2080").unwrap();
+    let next = schedule.next_time_from(time::macros::date!(2021-06-01).midnight().assume_utc());
+    assert_eq!(next.date(), time::macros::date!(2050-01-01));
+    let prev = schedule.prev_time_from(time::macros::date!(2079-06-01).midnight().assume_utc());
+    assert_eq!(prev.date(), time::macros::date!(2050-01-01));
+    let prev = schedule.prev_time_from(time::macros::date!(2199-06-01).midnight().assume_utc());
+    assert_eq!(prev.date(), time::macros::date!(2080-01-01));
+    assert_eq!(schedule.try_next_time_from(time::macros::date!(2080-06-01).midnight().assume_utc()), None);
+    assert_eq!(schedule.try_prev_time_from(time::macros::date!(2020-01-01).midnight().assume_utc()), None);
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2030-2190/40").unwrap();
+    let next = schedule.next_time_from(time::macros::date!(2031-06-01).midnight().assume_utc());
+    assert_eq!(next.date(), time::macros::date!(2070-01-01));
+    let prev = schedule.prev_time_from(time::macros::date!(2189-06-01).midnight().assume_utc());
+    assert_eq!(prev.date(), time::macros::date!(2150-01-01));
+
+    let time = time::macros::date!(2099-12-31).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 0 * * * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2100-01-01));
+}
+
+#[test]
+#[should_panic]
+fn should_panic_after_last_year() {
+    let time = time::macros::date!(2028-01-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2027").unwrap();
+    schedule.next_time_from(time);
+}
+
+#[test]
+fn should_schedule_on_last_day_of_month() {
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 L * *").unwrap();
+
+    let expected = [
+        time::macros::date!(2019-01-31),
+        time::macros::date!(2019-02-28),
+        time::macros::date!(2019-03-31),
+        time::macros::date!(2019-04-30),
+    ];
+    let mut prev = time;
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        assert_eq!(next.time(), time::macros::time!(00:00));
+        prev = next;
+    }
+
+    let time = time::macros::date!(2020-02-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2020-02-29));
+}
+
+#[test]
+fn should_schedule_on_offset_from_last_day_of_month() {
+    let time = time::macros::date!(2019-02-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 L-3 * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-25));
+
+    let time = time::macros::date!(2020-02-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2020-02-26));
+
+    let time = time::macros::date!(2019-12-28).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2020-01-28));
+
+    //Offset outside of month length is skipped
+    let time = time::macros::date!(2019-02-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 L-29 * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-03-02));
+
+    let schedule = CronSchedule::parse_str("0 0 10,L-2 * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-10));
+    let time = time::macros::date!(2019-02-10).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-26));
+}
+
+#[test]
+fn should_schedule_on_nearest_weekday() {
+    let schedule = CronSchedule::parse_str("0 0 15W * *").unwrap();
+
+    //Tuesday
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-15));
+
+    //Saturday moves to Friday
+    let time = time::macros::date!(2019-06-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-06-14));
+
+    //Sunday moves to Monday
+    let time = time::macros::date!(2019-09-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-09-16));
+
+    //Moved day already passed, so next month is taken
+    let time = time::macros::date!(2019-06-14).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-07-15));
+}
+
+#[test]
+fn should_schedule_on_nearest_weekday_without_crossing_month() {
+    //Saturday 1st moves to Monday 3rd
+    let schedule = CronSchedule::parse_str("0 0 1W * *").unwrap();
+    let time = time::macros::date!(2022-09-30).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2022-10-03));
+
+    //Sunday 1st moves to Monday 2nd
+    let time = time::macros::date!(2019-11-30).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-12-02));
+
+    //Sunday 31st moves to Friday 29th
+    let schedule = CronSchedule::parse_str("0 0 31W * *").unwrap();
+    let time = time::macros::date!(2019-03-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-03-29));
+
+    //Day outside of month is skipped
+    let time = time::macros::date!(2019-04-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-05-31));
+}
+
+#[test]
+fn should_schedule_on_last_weekday() {
+    let schedule = CronSchedule::parse_str("0 0 LW * *").unwrap();
+
+    let expected = [
+        //Thursday
+        time::macros::date!(2019-02-28),
+        //Sunday moves to Friday
+        time::macros::date!(2019-03-29),
+        time::macros::date!(2019-04-30),
+        time::macros::date!(2019-05-31),
+        time::macros::date!(2019-06-28),
+        time::macros::date!(2019-07-31),
+        //Saturday moves to Friday
+        time::macros::date!(2019-08-30),
+    ];
+
+    let mut prev = time::macros::date!(2019-02-01).midnight().assume_utc();
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        prev = next;
+    }
+}
+
+#[test]
+fn should_schedule_on_nth_day_of_week() {
+    let schedule = CronSchedule::parse_str("0 9 * * FRI#3").unwrap();
+
+    let expected = [
+        time::macros::date!(2019-01-18),
+        time::macros::date!(2019-02-15),
+        time::macros::date!(2019-03-15),
+    ];
+    let mut prev = time::macros::date!(2019-01-01).midnight().assume_utc();
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        assert_eq!(next.time(), time::macros::time!(09:00));
+        prev = next;
+    }
+
+    //Fifth Friday is skipped in months without it
+    let schedule = CronSchedule::parse_str("0 9 * * 5#5").unwrap();
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-03-29));
+
+    let schedule = CronSchedule::parse_str("0 9 * * MON#1,FRI#2").unwrap();
+    let time = time::macros::date!(2019-02-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-04));
+    let time = time::macros::date!(2019-02-04).with_hms(9, 0, 0).unwrap().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-08));
+}
+
+#[test]
+fn should_schedule_on_last_day_of_week() {
+    let schedule = CronSchedule::parse_str("0 9 * * SUNL").unwrap();
+
+    let expected = [
+        time::macros::date!(2019-01-27),
+        time::macros::date!(2019-02-24),
+        time::macros::date!(2019-03-31),
+    ];
+    let mut prev = time::macros::date!(2019-01-01).midnight().assume_utc();
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        prev = next;
+    }
+
+    let schedule = CronSchedule::parse_str("0 9 * * 5L").unwrap();
+    let time = time::macros::date!(2019-01-26).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-02-22));
+}
+
+#[test]
+fn should_schedule_on_either_day_of_month_or_day_of_week() {
+    let schedule = CronSchedule::parse_str("0 0 1 * MON").unwrap();
+    assert_eq!(schedule.day_matching(), cronchik::DayMatching::Or);
+
+    let expected = [
+        time::macros::date!(2019-01-01),
+        time::macros::date!(2019-01-07),
+        time::macros::date!(2019-01-14),
+        time::macros::date!(2019-01-21),
+        time::macros::date!(2019-01-28),
+        time::macros::date!(2019-02-01),
+        time::macros::date!(2019-02-04),
+    ];
+    let mut prev = time::macros::date!(2018-12-31).midnight().assume_utc();
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        prev = next;
+    }
+
+    //Wildcard in one of the fields makes only other field to matter
+    let schedule = CronSchedule::parse_str("0 0 * * MON").unwrap();
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-07));
+
+    let schedule = CronSchedule::parse_str("0 0 15 * *").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-15));
+}
+
+#[test]
+fn should_schedule_on_both_day_of_month_and_day_of_week() {
+    let options = cronchik::ParseOptions::new().day_matching(cronchik::DayMatching::And);
+    let schedule = CronSchedule::parse_str_with("0 0 1 * MON", options).unwrap();
+    assert_eq!(schedule.day_matching(), cronchik::DayMatching::And);
+
+    let time = time::macros::date!(2018-12-31).midnight().assume_utc();
+    let next = schedule.next_time_from(time);
+    assert_eq!(next.date(), time::macros::date!(2019-04-01));
+    assert_eq!(schedule.next_time_from(next).date(), time::macros::date!(2019-07-01));
+
+    let schedule = CronSchedule::parse_str_with("0 0 L * FRI", options).unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-05-31));
+}
+
+#[test]
+fn should_schedule_on_range_with_step() {
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 22 1-30/10 * *").unwrap();
+
+    let expected = [
+        time::macros::date!(2019-01-01),
+        time::macros::date!(2019-01-11),
+        time::macros::date!(2019-01-21),
+        time::macros::date!(2019-02-01),
+    ];
+    let mut prev = time;
+    for expected in expected.iter() {
+        let next = schedule.next_time_from(prev);
+        assert_eq!(next.date(), *expected);
+        assert_eq!(next.time(), time::macros::time!(22:00));
+        prev = next;
+    }
+}
+
+#[test]
+fn should_schedule_on_sunday_as_seven() {
+    let time = time::macros::date!(2019-01-01).midnight().assume_utc();
+    let schedule = CronSchedule::parse_str("0 0 * * 7").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-06));
+
+    let schedule = CronSchedule::parse_str("0 0 * January-March Saturday-7").unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-05));
+    let time = time::macros::date!(2019-01-05).midnight().assume_utc();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2019-01-06));
+}
+
+#[test]
+fn should_schedule_on_leap_day() {
+    let time = time::macros::datetime!(2097-03-01 00:00:00.5 +2);
+    let schedule = CronSchedule::parse_str("59 23 29 2 *").unwrap();
+
+    let next = schedule.next_time_from(time);
+    assert_eq!(next.date(), time::macros::date!(2104-02-29));
+    assert_eq!(next.time(), time::macros::time!(23:59));
+    assert_eq!(next.offset(), time::macros::offset!(+2));
+
+    let schedule = CronSchedule::parse_str_with("0 0 13 * FRI", cronchik::ParseOptions::new().day_matching(cronchik::DayMatching::And)).unwrap();
+    assert_eq!(schedule.next_time_from(time).date(), time::macros::date!(2097-09-13));
+}
+
+#[test]
+fn should_iterate_over_schedule() {
+    let time = time::macros::datetime!(2019-12-30 23:59:58.5 +3);
+    let expressions = ["* * * * * *", "*/20 * * * *", "0 22 1-30/10 * *", "0 0 L * *", "0 12 * * FRI#5", "30 4 1,15 * 5", "0 0 29 2 *"];
+
+    for expr in expressions.iter() {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let mut prev = time;
+        for next in schedule.iter_from(time).take(100) {
+            assert_eq!(next, schedule.next_time_from(prev), "'{}' after {}", expr, prev);
+            prev = next;
+        }
+    }
+
+    let schedule = CronSchedule::parse_str("0 0 * * *").unwrap();
+    let end = time::macros::datetime!(2020-01-05 00:00 +3);
+    let days = schedule.iter_from(time).take_while(|next| *next <= end).map(|next| next.day()).collect::<Vec<_>>();
+    assert_eq!(days, [31, 1, 2, 3, 4, 5]);
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2020-2022").unwrap();
+    let mut iter = schedule.iter_from(time);
+    assert_eq!(iter.clone().count(), 3);
+    assert_eq!(iter.next().unwrap().date(), time::macros::date!(2020-01-01));
+    assert_eq!(iter.next().unwrap().date(), time::macros::date!(2021-01-01));
+    assert_eq!(iter.next().unwrap().date(), time::macros::date!(2022-01-01));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn should_schedule_on_prev_time() {
+    let time = time::macros::datetime!(2021-03-01 00:00 +3);
+
+    let schedule = CronSchedule::parse_str("*/15 9-17 * * MON-FRI").unwrap();
+    assert_eq!(schedule.prev_time_from(time), time::macros::datetime!(2021-02-26 17:45 +3));
+
+    let schedule = CronSchedule::parse_str("0 0 L * *").unwrap();
+    assert_eq!(schedule.prev_time_from(time), time::macros::datetime!(2021-02-28 00:00 +3));
+    assert_eq!(schedule.prev_time_from(time::macros::datetime!(2021-02-28 00:00 +3)), time::macros::datetime!(2021-01-31 00:00 +3));
+
+    let schedule = CronSchedule::parse_str("0 0 31 * *").unwrap();
+    assert_eq!(schedule.prev_time_from(time), time::macros::datetime!(2021-01-31 00:00 +3));
+
+    let schedule = CronSchedule::parse_str("59 23 29 2 *").unwrap();
+    assert_eq!(schedule.prev_time_from(time), time::macros::datetime!(2020-02-29 23:59 +3));
+    assert_eq!(schedule.prev_time_from(time::macros::datetime!(2104-02-29 23:59 +3)), time::macros::datetime!(2096-02-29 23:59 +3));
+
+    let schedule = CronSchedule::parse_str("30 * * * * *").unwrap();
+    assert_eq!(schedule.prev_time_from(time::macros::datetime!(2021-03-01 00:00:30 UTC)), time::macros::datetime!(2021-02-28 23:59:30 UTC));
+    assert_eq!(schedule.prev_time_from(time::macros::datetime!(2021-03-01 00:00:30.1 UTC)), time::macros::datetime!(2021-03-01 00:00:30 UTC));
+
+    let schedule = CronSchedule::parse_str("0 0 12 * * FRI#1,5L 2020-2022").unwrap();
+    assert_eq!(schedule.prev_time_from(time::macros::datetime!(2030-01-01 00:00 UTC)), time::macros::datetime!(2022-12-30 12:00 UTC));
+    assert_eq!(schedule.try_prev_time_from(time::macros::datetime!(2020-01-03 12:00 UTC)), None);
+}
+
+#[test]
+fn should_iterate_over_schedule_in_reverse() {
+    let time = time::macros::datetime!(2020-01-01 00:00:01.5 +3);
+    let expressions = ["* * * * * *", "*/20 * * * *", "0 22 1-30/10 * *", "0 0 L-2,LW * *", "0 12 * * FRI#5", "30 4 1,15 * 5", "0 0 29 2 *"];
+
+    for expr in expressions.iter() {
+        let schedule = CronSchedule::parse_str(expr).unwrap();
+        let mut prev = time;
+        for next in schedule.rev_iter_from(time).take(100) {
+            assert_eq!(next, schedule.prev_time_from(prev), "'{}' before {}", expr, prev);
+            prev = next;
+        }
+    }
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2020-2022").unwrap();
+    let years = schedule.rev_iter_from(time::macros::datetime!(2030-01-01 00:00 UTC)).map(|prev| prev.year()).collect::<Vec<_>>();
+    assert_eq!(years, [2022, 2021, 2020]);
+}
+
+#[test]
+fn should_match_time() {
+    let schedule = CronSchedule::parse_str("*/15 9-17 * * MON-FRI").unwrap();
+    assert!(schedule.matches(time::macros::datetime!(2021-03-01 09:15 +3)));
+    assert!(schedule.matches(time::macros::datetime!(2021-03-01 09:15:00.999 +3)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-03-01 09:15:01 +3)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-03-01 09:16 +3)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-03-01 18:15 +3)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-02-28 09:15 +3)));
+
+    //Either day matches by default.
+    let schedule = CronSchedule::parse_str("0 0 13 * FRI").unwrap();
+    assert!(schedule.matches(time::macros::datetime!(2021-03-13 00:00 UTC)));
+    assert!(schedule.matches(time::macros::datetime!(2021-03-12 00:00 UTC)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-03-14 00:00 UTC)));
+
+    let schedule = CronSchedule::parse_str_with("0 0 13 * FRI", cronchik::ParseOptions::new().day_matching(cronchik::DayMatching::And)).unwrap();
+    assert!(!schedule.matches(time::macros::datetime!(2021-03-13 00:00 UTC)));
+    assert!(schedule.matches(time::macros::datetime!(2021-08-13 00:00 UTC)));
+
+    let schedule = CronSchedule::parse_str("0 0 LW * *").unwrap();
+    assert!(schedule.matches(time::macros::datetime!(2021-02-26 00:00 UTC)));
+    assert!(!schedule.matches(time::macros::datetime!(2021-02-28 00:00 UTC)));
+
+    let schedule = CronSchedule::parse_str("30 0 0 * * * 2021").unwrap();
+    assert!(schedule.matches(time::macros::datetime!(2021-02-28 00:00:30 UTC)));
+    assert!(!schedule.matches(time::macros::datetime!(2022-02-28 00:00:30 UTC)));
+    assert!(!schedule.matches(time::macros::datetime!(1900-02-28 00:00:30 UTC)));
+}
+
+#[test]
+fn should_count_occurrences_between() {
+    let start = time::macros::datetime!(2021-10-01 00:00 UTC);
+    let end = time::macros::datetime!(2022-01-01 00:00 UTC);
+
+    let schedule = CronSchedule::parse_str("* * * * *").unwrap();
+    assert_eq!(schedule.count_between(start, end), 92 * 24 * 60);
+    assert_eq!(schedule.count_between(start, start), 0);
+    assert_eq!(schedule.count_between(end, start), 0);
+    //Range is half-open and fraction of second is respected.
+    assert_eq!(schedule.count_between(start, start + time::Duration::minutes(1)), 1);
+    assert_eq!(schedule.count_between(start + time::Duration::nanoseconds(1), start + time::Duration::minutes(1)), 0);
+    assert_eq!(schedule.count_between(start, start + time::Duration::seconds(60) + time::Duration::nanoseconds(1)), 2);
+
+    let schedule = CronSchedule::parse_str("* * * * * *").unwrap();
+    assert_eq!(schedule.count_between(time::macros::datetime!(2021-01-01 00:00 UTC), time::macros::datetime!(2022-01-01 00:00 UTC)), 365 * 24 * 60 * 60);
+
+    let schedule = CronSchedule::parse_str("*/15 9-17 * * MON-FRI").unwrap();
+    let days = schedule.occurrences_between(start, end).filter(|next| next.time() == time::macros::time!(09:00)).count() as u64;
+    assert_eq!(days, 66);
+    assert_eq!(schedule.count_between(start, end), days * 9 * 4);
+    assert_eq!(schedule.occurrences_between(start, end).count() as u64, days * 9 * 4);
+
+    //End in other offset.
+    let schedule = CronSchedule::parse_str("0 0 * * *").unwrap();
+    let end = time::macros::datetime!(2021-10-03 00:00 +3);
+    assert_eq!(schedule.count_between(start, end), 2);
+    assert_eq!(schedule.occurrences_between(start, end).collect::<Vec<_>>(), [start, time::macros::datetime!(2021-10-02 00:00 UTC)]);
+}
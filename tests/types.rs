@@ -1,9 +1,14 @@
 macro_rules! assert_test {
     ($ty:ty) => {
+        assert_test!($ty, iter);
+    };
+    //`values` returns set of values of parsed expression.
+    ($ty:ty, $values:ident) => {
         use core::fmt::Write;
 
         type Type = $ty;
-        let result = Type::from_expr("*").unwrap();
+        let parse = |text: &str| Type::from_expr(text).map(|result| result.$values().into_iter().collect::<Vec<_>>());
+        let result = parse("*").unwrap();
         println!("{}: Verify *", stringify!($ty));
         for idx in 1..=((Type::MAX - Type::MIN) as usize) {
             let val: u8 = result[idx].into();
@@ -14,7 +19,7 @@ macro_rules! assert_test {
         println!("{}: Verify parser from MIN to MAX", stringify!($ty));
         for idx in Type::MIN..=Type::MAX {
             let text = format!("{}", idx);
-            let result = Type::from_expr(&text).unwrap();
+            let result = parse(&text).unwrap();
             assert_eq!(result.len(), 1);
             let val: u8 = result[0].into();
             assert_eq!(val, idx);
@@ -25,14 +30,14 @@ macro_rules! assert_test {
         let mut expected_len = 1;
         for idx in Type::MIN..=Type::MAX {
             let _ = write!(&mut list, "{}", idx);
-            let result = Type::from_expr(&list).expect("To parse list");
+            let result = parse(&list).expect("To parse list");
             assert_eq!(result.len(), expected_len, "wrong len");
             expected_len += 1;
             list.push(',');
         }
 
         println!("{}: Verify list repeats", stringify!($ty));
-        let result = Type::from_expr("2,1,2,1").expect("To parse list");
+        let result = parse("2,1,2,1").expect("To parse list");
         assert_eq!(result.len(), 2, "wrong len");
         let val: u8  = result[0].into();
         assert_eq!(val, 1);
@@ -40,28 +45,48 @@ macro_rules! assert_test {
         assert_eq!(val, 2);
 
         println!("{}: Verify step by 5", stringify!($ty));
-        let result = Type::from_expr("*/5").expect("To parse list");
+        let result = parse("*/5").expect("To parse list");
         for (idx, expected_val) in (Type::MIN..=Type::MAX).step_by(5).enumerate() {
             let val: u8 = result[idx].into();
             assert_eq!(val, expected_val);
         }
 
         println!("{}: Verify step by 5 starting from 3", stringify!($ty));
-        let result = Type::from_expr("3/5").expect("To parse list");
+        let result = parse("3/5").expect("To parse list");
         for (idx, expected_val) in (3..=Type::MAX).step_by(5).enumerate() {
             let val: u8 = result[idx].into();
             assert_eq!(val, expected_val);
         }
 
+        println!("{}: Verify step by 3 within range", stringify!($ty));
+        let text = format!("{}-{}/3", Type::MIN + 1, Type::MAX - 1);
+        let result = parse(&text).expect("To parse range with step");
+        let expected: Vec<u8> = (Type::MIN + 1..=Type::MAX - 1).step_by(3).collect();
+        assert_eq!(result.len(), expected.len(), "wrong len");
+        for (idx, expected_val) in expected.into_iter().enumerate() {
+            let val: u8 = result[idx].into();
+            assert_eq!(val, expected_val);
+        }
+
+        println!("{}: Verify reversed range with step", stringify!($ty));
+        let text = format!("{}-{}/3", Type::MAX, Type::MIN);
+        parse(&text).expect_err("Should fail reversed range");
+
         println!("{}: Verify step by 0", stringify!($ty));
-        Type::from_expr("*/0").expect_err("Should fail step by 0");
+        parse("*/0").expect_err("Should fail step by 0");
     }
 }
 
 #[test]
 fn assert_day_of_month_parser() {
     use cronchik::DayOfMonth;
-    assert_test!(DayOfMonth);
+    assert_test!(DayOfMonth, days);
+}
+
+#[test]
+fn assert_second_parser() {
+    use cronchik::Second;
+    assert_test!(Second);
 }
 
 #[test]
@@ -79,7 +104,7 @@ fn assert_hour_parser() {
 #[test]
 fn assert_day_parser() {
     use cronchik::Day;
-    assert_test!(Day);
+    assert_test!(Day, days);
 }
 
 #[test]
@@ -96,6 +121,10 @@ fn assert_display_correctness() {
         "0 20 10 * *",
         "0 20 * * SAT",
         "0 20 * MAR FRI",
+        "* 1 1 * * *",
+        "30 0 20 * MAR FRI",
+        "0 0 L * *",
+        "0 0 1,L-3 * *",
     ];
 
     for cron in crons.iter() {
@@ -111,7 +140,10 @@ fn assert_display_correctness() {
         ("0 20 * MAR/2 FRI", "0 20 * MAR,MAY,JUL,SEP,NOV FRI"),
         ("0 1,10-20 * MAR/2 FRI", "0 1,10-20 * MAR,MAY,JUL,SEP,NOV FRI"),
         ("0 1,10/2 * MAR/2 FRI", "0 1,10,12,14,16,18,20,22 * MAR,MAY,JUL,SEP,NOV FRI"),
+        ("10-30/10 1-9/4 * MAR-AUG/2 MON-FRI/2", "10,20,30 1,5,9 * MAR,MAY,JUL MON,WED,FRI"),
         ("10,20,30/10 1,10/2 * MAR/2 FRI", "10,20,30,40,50 1,10,12,14,16,18,20,22 * MAR,MAY,JUL,SEP,NOV FRI"),
+        ("0 1 1 * * *", "1 1 * * *"),
+        ("*/15 * * * * *", "0,15,30,45 * * * * *"),
     ];
 
     for (cron, expected_rev) in crons.iter() {
@@ -122,3 +154,440 @@ fn assert_display_correctness() {
         assert_eq!(schedule, rev_schedule);
     }
 }
+
+#[test]
+fn assert_year_parser() {
+    use cronchik::Year;
+
+    let result = Year::from_expr("*").unwrap();
+    assert_eq!(result.len(), (Year::MAX - Year::MIN) as usize + 1);
+
+    let result = Year::from_expr("2027").unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), [Year::from_num(2027).unwrap()]);
+
+    let result = Year::from_expr("2046,2026/20").unwrap();
+    let result: Vec<u16> = result.iter().map(|year| year.into()).collect();
+    assert_eq!(result, [2026, 2046, 2066, 2086, 2106, 2126, 2146, 2166, 2186]);
+
+    let result = Year::from_expr("2026-2028,2030").unwrap();
+    let result: Vec<u16> = result.iter().map(|year| year.into()).collect();
+    assert_eq!(result, [2026, 2027, 2028, 2030]);
+
+    Year::from_expr("1969").expect_err("Should fail on year before MIN");
+    Year::from_expr("2200").expect_err("Should fail on year after MAX");
+    Year::from_expr("2028-2026").expect_err("Should fail on reversed range");
+    Year::from_expr("*/0").expect_err("Should fail step by 0");
+}
+
+#[test]
+fn assert_year_in_schedule() {
+    use cronchik::CronSchedule;
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2027").unwrap();
+    assert_eq!(schedule.years().unwrap().len(), 1);
+    assert_eq!(format!("{}", schedule), "0 0 0 1 JAN * 2027");
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * *").unwrap();
+    assert!(schedule.years().is_none());
+    assert_eq!(format!("{}", schedule), "0 0 1 JAN *");
+    assert_eq!(schedule, CronSchedule::parse_str("0 0 1 1 *").unwrap());
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2026/20").unwrap();
+    assert_eq!(format!("{}", schedule), "0 0 0 1 JAN * 2026,2046,2066,2086,2106,2126,2146,2166,2186");
+    assert_eq!(schedule, CronSchedule::parse_str(&format!("{}", schedule)).unwrap());
+
+    CronSchedule::parse_str("0 0 0 1 1 * 2027 1").expect_err("Should fail on 8 fields");
+
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 1970-2199").unwrap();
+    assert!(schedule.years().is_none());
+    assert_eq!(schedule, CronSchedule::parse_str("0 0 0 1 1 * *").unwrap());
+    let text = format!("{}", schedule);
+    assert_eq!(text, "0 0 1 JAN *");
+    assert_eq!(CronSchedule::parse_str(&text).unwrap(), schedule);
+    CronSchedule::parse_str("0 0 0 1 1 * 1900").expect_err("Should fail on invalid year");
+}
+
+#[test]
+fn assert_nicknames() {
+    use cronchik::CronSchedule;
+
+    let nicknames = [
+        ("@yearly", cronchik::YEARLY, "@yearly"),
+        ("@ANNUALLY", cronchik::YEARLY, "@yearly"),
+        ("@monthly", cronchik::MONTHLY, "@monthly"),
+        ("@Weekly", cronchik::WEEKLY, "@weekly"),
+        ("@daily", cronchik::DAILY, "@daily"),
+        (" @midnight ", cronchik::DAILY, "@daily"),
+        ("@hourly", cronchik::HOURLY, "@hourly"),
+    ];
+
+    for (nickname, expr, expected_nickname) in nicknames.iter() {
+        let schedule = CronSchedule::parse_str(nickname).unwrap();
+        let expected = CronSchedule::parse_str(expr).unwrap();
+        assert_eq!(schedule, expected);
+        assert_eq!(format!("{}", schedule), format!("{}", expected));
+        assert_eq!(format!("{:#}", schedule), *expected_nickname);
+    }
+
+    let schedule = CronSchedule::parse_str("0 0 1 1 MON").unwrap();
+    assert_eq!(format!("{:#}", schedule), "0 0 1 JAN MON");
+
+    //Day matching does not matter, when day fields are not both restricted.
+    let options = cronchik::ParseOptions::new().day_matching(cronchik::DayMatching::And);
+    let schedule = CronSchedule::parse_str_with("0 0 * * *", options).unwrap();
+    assert_eq!(format!("{:#}", schedule), "@daily");
+    let schedule = CronSchedule::parse_str_with("@weekly", options).unwrap();
+    assert_eq!(format!("{:#}", schedule), "@weekly");
+    let schedule = CronSchedule::parse_str_with("0 0 1 * 0", options).unwrap();
+    assert_eq!(format!("{:#}", schedule), "0 0 1 * SUN");
+
+    CronSchedule::parse_str("@reboot").expect_err("Should fail on @reboot");
+    CronSchedule::parse_str("@daily 1").expect_err("Should fail on extra fields");
+    CronSchedule::parse_str("@").expect_err("Should fail on empty nickname");
+}
+
+#[test]
+fn assert_day_of_month_last_day_parser() {
+    use cronchik::DayOfMonth;
+
+    let result = DayOfMonth::from_expr("L").unwrap();
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.last_day_offsets().collect::<Vec<_>>(), [0]);
+    assert_eq!(format!("{}", result), "L");
+
+    let result = DayOfMonth::from_expr("L-3,1,L,2,L-3,15").unwrap();
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.last_day_offsets().collect::<Vec<_>>(), [0, 3]);
+    assert_eq!(format!("{}", result), "1-2,15,L,L-3");
+
+    let result = DayOfMonth::from_expr("l-30").unwrap();
+    assert_eq!(result.last_day_offsets().collect::<Vec<_>>(), [30]);
+
+    DayOfMonth::from_expr("L-31").expect_err("Should fail on offset outside of month");
+    DayOfMonth::from_expr("L-").expect_err("Should fail on missing offset");
+    DayOfMonth::from_expr("L3").expect_err("Should fail on invalid offset");
+    DayOfMonth::from_expr("L,*").expect_err("Should fail on wildcard with L");
+}
+
+#[test]
+fn assert_day_of_month_nearest_weekday_parser() {
+    use cronchik::DayOfMonth;
+
+    let result = DayOfMonth::from_expr("15W").unwrap();
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.nearest_weekdays().iter().collect::<Vec<_>>(), [DayOfMonth::from_num(15).unwrap()]);
+    assert!(!result.last_weekday());
+    assert_eq!(format!("{}", result), "15W");
+
+    let result = DayOfMonth::from_expr("lw").unwrap();
+    assert!(result.last_weekday());
+    assert_eq!(format!("{}", result), "LW");
+
+    let result = DayOfMonth::from_expr("LW,15W,1,1w,L").unwrap();
+    assert_eq!(format!("{}", result), "1,L,1W,15W,LW");
+
+    DayOfMonth::from_expr("W").expect_err("Should fail on missing day");
+    DayOfMonth::from_expr("0W").expect_err("Should fail on invalid day");
+    DayOfMonth::from_expr("32W").expect_err("Should fail on invalid day");
+    DayOfMonth::from_expr("L-2W").expect_err("Should fail on offset with W");
+}
+
+#[test]
+fn assert_day_of_week_modifiers_parser() {
+    use cronchik::Day;
+
+    let result = Day::from_expr("FRI#3").unwrap();
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.nth_days().collect::<Vec<_>>(), [(Day::Friday, 3)]);
+    assert_eq!(format!("{}", result), "FRI#3");
+
+    let result = Day::from_expr("5L").unwrap();
+    assert_eq!(result.last_days().iter().collect::<Vec<_>>(), [Day::Friday]);
+    assert_eq!(format!("{}", result), "FRIL");
+
+    let result = Day::from_expr("sunl,MON,1#1,0#5,mon#1").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Monday]);
+    assert_eq!(result.nth_days().collect::<Vec<_>>(), [(Day::Sunday, 5), (Day::Monday, 1)]);
+    assert_eq!(result.last_days().iter().collect::<Vec<_>>(), [Day::Sunday]);
+    assert_eq!(format!("{}", result), "MON,SUN#5,MON#1,SUNL");
+    assert_eq!(Day::from_expr(&format!("{}", result)).unwrap(), result);
+
+    Day::from_expr("FRI#0").expect_err("Should fail on zero week");
+    Day::from_expr("FRI#6").expect_err("Should fail on week outside of month");
+    Day::from_expr("FRI#").expect_err("Should fail on missing week");
+    Day::from_expr("#1").expect_err("Should fail on missing day");
+    Day::from_expr("L").expect_err("Should fail on missing day");
+    Day::from_expr("FRI#1#2").expect_err("Should fail on multiple #");
+}
+
+#[test]
+fn assert_range_with_step_parser() {
+    use cronchik::{Day, Month, Minute, Year};
+
+    let result = Minute::from_expr("10-50/5").unwrap();
+    let result: Vec<u8> = result.iter().map(|minute| minute.into()).collect();
+    assert_eq!(result, [10, 15, 20, 25, 30, 35, 40, 45, 50]);
+
+    let result = Day::from_expr("MON-FRI/2").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Monday, Day::Wednesday, Day::Friday]);
+
+    let result = Day::from_expr("1-fri/2,SUN").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Monday, Day::Wednesday, Day::Friday]);
+
+    let result = Month::from_expr("jan-JUN/2").unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), [Month::January, Month::March, Month::May]);
+
+    let result = Year::from_expr("2026-2030/2").unwrap();
+    let result: Vec<u16> = result.iter().map(|year| year.into()).collect();
+    assert_eq!(result, [2026, 2028, 2030]);
+
+    Minute::from_expr("10-50/0").expect_err("Should fail step by 0");
+    Minute::from_expr("10-50/a").expect_err("Should fail invalid step");
+    Minute::from_expr("10-60/5").expect_err("Should fail invalid range");
+    Minute::from_expr("10-20-30/5").expect_err("Should fail invalid range");
+    Day::from_expr("FRI-MON/2").expect_err("Should fail reversed range");
+}
+
+#[test]
+fn assert_wrapping_range_parser() {
+    use cronchik::{Day, Hour, Month, ParseOptions};
+
+    let options = ParseOptions::new().wrap_ranges(true);
+
+    let result = Hour::from_expr_with("22-2", options).unwrap();
+    let result: Vec<u8> = result.iter().map(|hour| hour.into()).collect();
+    assert_eq!(result, [0, 1, 2, 22, 23]);
+
+    let result = Hour::from_expr_with("22-4/2", options).unwrap();
+    let result: Vec<u8> = result.iter().map(|hour| hour.into()).collect();
+    assert_eq!(result, [0, 2, 4, 22]);
+
+    let result = Day::from_expr_with("FRI-MON", options).unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Monday, Day::Friday, Day::Saturday]);
+
+    let result = Month::from_expr_with("NOV-FEB", options).unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), [Month::January, Month::February, Month::November, Month::December]);
+
+    //Non reversed ranges are unaffected
+    let result = Hour::from_expr_with("2-4", options).unwrap();
+    let result: Vec<u8> = result.iter().map(|hour| hour.into()).collect();
+    assert_eq!(result, [2, 3, 4]);
+
+    Hour::from_expr("22-2").expect_err("Should fail reversed range in strict mode");
+    Day::from_expr("FRI-MON").expect_err("Should fail reversed range in strict mode");
+
+    let schedule = cronchik::CronSchedule::parse_str_with("0 22-2 * * FRI-MON", options).unwrap();
+    assert_eq!(format!("{}", schedule), "0 0-2,22-23 * * SUN-MON,FRI-SAT");
+    cronchik::CronSchedule::parse_str("0 22-2 * * FRI-MON").expect_err("Should fail reversed range in strict mode");
+}
+
+#[test]
+fn assert_day_sunday_as_seven_and_full_names() {
+    use cronchik::{Day, Month};
+
+    assert_eq!(Day::from_bytes(b"7"), Some(Day::Sunday));
+    assert_eq!(Day::from_bytes(b"8"), None);
+    assert_eq!(Day::from_bytes(b"MONDAY"), Some(Day::Monday));
+    assert_eq!(Day::from_bytes(b"wednesday"), Some(Day::Wednesday));
+    assert_eq!(Day::from_bytes(b"Sat"), Some(Day::Saturday));
+    assert_eq!(Day::from_bytes(b"SATUR"), None);
+    assert_eq!(Day::from_bytes(b"SU"), None);
+
+    assert_eq!(Month::from_bytes(b"September"), Some(Month::September));
+    assert_eq!(Month::from_bytes(b"MAY"), Some(Month::May));
+    assert_eq!(Month::from_bytes(b"june"), Some(Month::June));
+    assert_eq!(Month::from_bytes(b"JUNEE"), None);
+
+    let result = Day::from_expr("7").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday]);
+
+    let result = Day::from_expr("5-7").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Friday, Day::Saturday]);
+
+    let result = Day::from_expr("MONDAY-sunday").unwrap();
+    assert_eq!(result.len(), 7);
+
+    let result = Day::from_expr("1-7/2").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Monday, Day::Wednesday, Day::Friday]);
+
+    let result = Day::from_expr("0-7").unwrap();
+    assert_eq!(result.len(), 7);
+
+    let result = Day::from_expr("SUN-7").unwrap();
+    assert_eq!(result.len(), 7);
+
+    let schedule = cronchik::CronSchedule::parse_str("0 0 * * 0-7").unwrap();
+    assert!(schedule.days_of_week().is_any());
+    assert_eq!(format!("{}", schedule), "0 0 * * *");
+
+    let result = Day::from_expr("SUN-SUN").unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday]);
+
+    let result = Day::from_expr("Friday#3,saturdayL").unwrap();
+    assert_eq!(result.nth_days().collect::<Vec<_>>(), [(Day::Friday, 3)]);
+    assert_eq!(result.last_days().iter().collect::<Vec<_>>(), [Day::Saturday]);
+
+    let result = Month::from_expr("March-May,December").unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), [Month::March, Month::April, Month::May, Month::December]);
+
+    Day::from_expr("5-0").expect_err("Should fail reversed range ending with 0");
+    Day::from_expr("8").expect_err("Should fail on 8");
+    Day::from_expr("MONDAYS").expect_err("Should fail on invalid name");
+    Month::from_expr("Sept").expect_err("Should fail on invalid name");
+}
+
+#[test]
+fn assert_quartz_dialect() {
+    use cronchik::{CronSchedule, Day, DayOfMonth, Dialect, InvalidExpr, ParseError, ParseOptions, Year};
+
+    let options = ParseOptions::new().dialect(Dialect::Quartz);
+
+    let result = Day::from_expr_with("1-7", options).unwrap();
+    assert_eq!(result.len(), 7);
+    let result = Day::from_expr_with("2-6", options).unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Monday, Day::Tuesday, Day::Wednesday, Day::Thursday, Day::Friday]);
+    let result = Day::from_expr_with("1,L", options).unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Saturday]);
+    let result = Day::from_expr_with("6#3,6L", options).unwrap();
+    assert_eq!(result.nth_days().collect::<Vec<_>>(), [(Day::Friday, 3)]);
+    assert_eq!(result.last_days().iter().collect::<Vec<_>>(), [Day::Friday]);
+    let result = Day::from_expr_with("FRI-SUN", ParseOptions::new().dialect(Dialect::Quartz).wrap_ranges(true)).unwrap();
+    assert_eq!(result.days().iter().collect::<Vec<_>>(), [Day::Sunday, Day::Friday, Day::Saturday]);
+    assert!(Day::from_expr_with("?", options).unwrap().is_any());
+    assert!(DayOfMonth::from_expr_with("?", options).unwrap().is_any());
+
+    Day::from_expr_with("0", options).expect_err("Should fail on 0");
+    Day::from_expr_with("8", options).expect_err("Should fail on 8");
+    Day::from_expr_with("?,1", options).expect_err("Should fail on ? in list");
+    Day::from_expr("?").expect_err("Should fail on ? in regular dialect");
+    DayOfMonth::from_expr("?").expect_err("Should fail on ? in regular dialect");
+
+    let crons = [
+        ("0 0/15 8-17 ? * MON-FRI", "0 */15 8-17 * * MON-FRI", "0 0,15,30,45 8-17 ? * MON-FRI"),
+        ("0 0 12 * * ?", "0 0 12 * * *", "0 0 12 * * ?"),
+        ("0 15 10 ? * 6L 2026-2027", "0 15 10 * * FRIL 2026-2027", "0 15 10 ? * FRIL 2026-2027"),
+        ("0 15 10 L-2 * ? *", "0 15 10 L-2 * *", "0 15 10 L-2 * ?"),
+        ("30 0 9 15W JAN ?", "30 0 9 15W JAN *", "30 0 9 15W JAN ?"),
+        ("0 0 0 ? * 1#2", "0 0 0 * * SUN#2", "0 0 0 ? * SUN#2"),
+    ];
+
+    for (cron, expected, expected_quartz) in crons.iter() {
+        let schedule = CronSchedule::parse_quartz_str(cron).unwrap();
+        assert_eq!(schedule, CronSchedule::parse_str(expected).unwrap());
+        let text = format!("{}", schedule.display_quartz());
+        assert_eq!(text, *expected_quartz);
+        assert_eq!(schedule, CronSchedule::parse_quartz_str(&text).unwrap());
+    }
+
+    CronSchedule::parse_quartz_str("0 12 * * ?").expect_err("Should fail on missing seconds");
+    CronSchedule::parse_quartz_str("0 0 12 * * ? 2026 1").expect_err("Should fail on 8 fields");
+    CronSchedule::parse_quartz_str("@daily").expect_err("Should fail on nickname");
+    CronSchedule::parse_str("0 0 12 ? * MON").expect_err("Should fail on ? in regular dialect");
+
+    assert!(matches!(CronSchedule::parse_quartz_str("0 0 12 ? * ?"), Err(ParseError::InvalidDays)));
+    assert!(matches!(CronSchedule::parse_quartz_str("0 0 12 15 * 2"), Err(ParseError::InvalidDays)));
+    assert!(matches!(CronSchedule::parse_quartz_str("0 0 12 L * 6L"), Err(ParseError::InvalidDays)));
+    assert!(CronSchedule::parse_quartz_str("0 0 12 * * *").is_ok());
+    assert!(CronSchedule::parse_quartz_str("0 0 12 ? * *").is_ok());
+
+    assert!(matches!(CronSchedule::parse_quartz_str("0 0 12 ? * * 2100"), Err(ParseError::InvalidExpr(Year::NAME, InvalidExpr::InvalidEntryRange))));
+    assert!(matches!(CronSchedule::parse_quartz_str("0 0 12 ? * * 2090-2100"), Err(ParseError::InvalidExpr(Year::NAME, _))));
+    let schedule = CronSchedule::parse_quartz_str("0 0 12 ? * * 2026/20").unwrap();
+    assert_eq!(schedule, CronSchedule::parse_str("0 0 12 * * * 2026-2086/20").unwrap());
+    let schedule = CronSchedule::parse_quartz_str("0 0 12 ? * * 2099").unwrap();
+    assert_eq!(format!("{}", schedule.display_quartz()), "0 0 12 * * ? 2099");
+    assert!(CronSchedule::parse_str("0 0 12 * * * 2100").is_ok());
+
+    //Schedules unsupported by Quartz are written as is, but cannot be parsed back.
+    let schedule = CronSchedule::parse_str("0 0 1 * MON").unwrap();
+    let text = format!("{}", schedule.display_quartz());
+    assert_eq!(text, "0 0 0 1 * MON");
+    assert!(matches!(CronSchedule::parse_quartz_str(&text), Err(ParseError::InvalidDays)));
+    let schedule = CronSchedule::parse_str("0 0 0 1 1 * 2150").unwrap();
+    let text = format!("{}", schedule.display_quartz());
+    assert_eq!(text, "0 0 0 1 JAN ? 2150");
+    assert!(matches!(CronSchedule::parse_quartz_str(&text), Err(ParseError::InvalidExpr(Year::NAME, _))));
+}
+
+#[test]
+fn assert_values_set() {
+    use cronchik::{CronSchedule, Day, Hour, Minute, Year};
+
+    let schedule = CronSchedule::parse_str("0 */15 9-17 1,L * MON-FRI,SAT#2 2026-2030/2").unwrap();
+    let minutes = schedule.minutes();
+    assert_eq!(minutes.len(), 4);
+    assert!(minutes.contains(Minute::from_num(45).unwrap()));
+    assert!(!minutes.contains(Minute::from_num(44).unwrap()));
+    assert_eq!(minutes.iter().rev().map(|minute| minute.into()).collect::<Vec<u8>>(), [45, 30, 15, 0]);
+    assert_eq!(format!("{:?}", minutes), "[Minute(0), Minute(15), Minute(30), Minute(45)]");
+
+    let hours = schedule.hours();
+    assert!(!hours.is_all());
+    assert!(hours.contains(Hour::from_num(17).unwrap()));
+    assert_eq!(hours.iter().next_back(), Hour::from_num(17));
+    assert!(schedule.months().is_all());
+
+    assert_eq!(schedule.days_of_week().days().iter().collect::<Vec<_>>(), [Day::Monday, Day::Tuesday, Day::Wednesday, Day::Thursday, Day::Friday]);
+    assert_eq!(schedule.days_of_week().nth_days().collect::<Vec<_>>(), [(Day::Saturday, 2)]);
+    assert_eq!(schedule.days_of_month().last_day_offsets().collect::<Vec<_>>(), [0]);
+
+    let years = schedule.years().unwrap();
+    assert_eq!(years.len(), 3);
+    assert!(years.contains(Year::from_num(2028).unwrap()));
+    assert!(!years.contains(Year::from_num(2029).unwrap()));
+    assert_eq!(years.iter().rev().map(|year| year.into()).collect::<Vec<u16>>(), [2030, 2028, 2026]);
+    assert!(Year::from_expr("*").unwrap().is_all());
+    assert_eq!(Year::from_expr("2099").unwrap().iter().next(), Year::from_num(2099));
+    assert_eq!(Year::from_expr("2199").unwrap().iter().next(), Year::from_num(2199));
+}
+
+#[test]
+fn assert_years_storage() {
+    use cronchik::{CronSchedule, InvalidExpr, ParseError, Year};
+
+    fn years(text: &str) -> Vec<u16> {
+        Year::from_expr(text).unwrap().iter().map(|year| year.into()).collect()
+    }
+
+    //Evenly spaced years
+    assert_eq!(years("2000-2199/50"), [2000, 2050, 2100, 2150]);
+    assert_eq!(years("1971-2199").len(), 229);
+    assert_eq!(years("2199,1970"), [1970, 2199]);
+    assert_eq!(Year::from_expr("1971-2199").unwrap().iter().next_back(), Year::from_num(2199));
+    //Years within 64 steps of common interval
+    assert_eq!(years("2026,2027,2030"), [2026, 2027, 2030]);
+    assert_eq!(years("2026,2036,2056"), [2026, 2036, 2056]);
+    assert_eq!(years("1970/10,1980-2199/20"), years("1970-2190/10"));
+    assert_eq!(years("2000,2002,2010-2100/10"), [2000, 2002].iter().copied().chain((2010..=2100).step_by(10)).collect::<Vec<_>>());
+
+    let result = Year::from_expr("2000,2010,2020,2060,2190").unwrap();
+    assert_eq!(result.len(), 5);
+    assert!(result.contains(Year::from_num(2060).unwrap()));
+    assert!(!result.contains(Year::from_num(2030).unwrap()));
+    assert!(!result.contains(Year::from_num(2065).unwrap()));
+    assert!(!result.contains(Year::from_num(1990).unwrap()));
+    assert_eq!(result.iter().rev().map(|year| year.into()).collect::<Vec<u16>>(), [2190, 2060, 2020, 2010, 2000]);
+    assert_eq!(result, Year::from_expr("2190,2060,2000-2020/10").unwrap());
+
+    assert!(matches!(Year::from_expr("2000,2001,2064"), Err(InvalidExpr::SparseYears)));
+    assert!(matches!(Year::from_expr("1970,1972-2199"), Err(InvalidExpr::SparseYears)));
+    assert!(matches!(Year::from_expr("2000,2002,2010-2190/10"), Err(InvalidExpr::SparseYears)));
+    assert!(matches!(CronSchedule::parse_str("0 0 0 1 1 * 2000,2001,2100"), Err(ParseError::InvalidExpr(Year::NAME, InvalidExpr::SparseYears))));
+}
+
+#[test]
+fn assert_impossible_date() {
+    use cronchik::{CronSchedule, DayMatching, ParseError, ParseOptions};
+
+    for expr in ["0 0 30 2 *", "0 0 31 4,6 *", "0 0 30,31 FEB *", "0 0 L-29 2 *", "0 0 0 29 2 * 2021-2023", "0 0 0 * 2 MON#5 2021-2023"].iter() {
+        assert!(matches!(CronSchedule::parse_str(expr), Err(ParseError::ImpossibleDate)), "'{}' should be impossible", expr);
+    }
+    let options = ParseOptions::new().day_matching(DayMatching::And);
+    assert!(matches!(CronSchedule::parse_str_with("0 0 1-7 * MON#2", options), Err(ParseError::ImpossibleDate)));
+    assert_eq!(format!("{}", ParseError::ImpossibleDate), "Cron expression never matches any date");
+
+    for expr in ["0 0 29 2 *", "0 0 31 4,5 *", "0 0 30 2 MON", "0 0 L-28 2 *", "0 0 * 2 MON#5", "0 0 0 29 2 * 2021-2024", "0 0 13 * FRI"].iter() {
+        assert!(CronSchedule::parse_str(expr).is_ok(), "'{}' should be possible", expr);
+    }
+    assert!(CronSchedule::parse_str_with("0 0 13 * FRI", options).is_ok());
+}
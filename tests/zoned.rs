@@ -0,0 +1,256 @@
+//Schedules are checked against known time zone rules, hence bundled database is required rather than host one.
+#![cfg(feature = "tz-bundled")]
+
+use cronchik::zoned::{load_time_zone, DstPolicy, GapPolicy, OverlapPolicy, ZonedParseError, ZonedSchedule};
+use cronchik::{CronSchedule, ParseError};
+
+#[test]
+fn verify_zoned_parser() {
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 9 * * *").unwrap();
+    assert_eq!(schedule.time_zone_name(), Some("Europe/Berlin"));
+    assert_eq!(*schedule.schedule(), CronSchedule::parse_str("0 9 * * *").unwrap());
+    assert_eq!(format!("{}", schedule), "CRON_TZ=Europe/Berlin 0 9 * * *");
+
+    let schedule = ZonedSchedule::parse_str(" TZ=America/New_York   @daily ").unwrap();
+    assert_eq!(schedule.time_zone_name(), Some("America/New_York"));
+    assert_eq!(format!("{}", schedule), "CRON_TZ=America/New_York 0 0 * * *");
+    assert_eq!(format!("{:#}", schedule), "CRON_TZ=America/New_York @daily");
+    assert_eq!(ZonedSchedule::parse_str(&format!("{}", schedule)).unwrap(), schedule);
+
+    let schedule = ZonedSchedule::parse_str("0 9 * * *").unwrap();
+    assert_eq!(schedule.time_zone_name(), None);
+    assert_eq!(format!("{}", schedule), "0 9 * * *");
+
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=Mars/Olympus 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ= 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    //Time zone is looked up only by IANA identifier.
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=/etc/passwd 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=/usr/share/zoneinfo/UTC 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=Europe/../../../../etc/passwd 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=Europe/ 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    //POSIX TZ rules are not time zone names.
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=XYZ3 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=CET-1CEST,M3.5.0,M10.5.0/3 0 9 * * *"), Err(ZonedParseError::UnknownTimeZone)));
+    assert!(load_time_zone("Etc/GMT+3").is_some());
+    assert!(load_time_zone("/etc/passwd").is_none());
+    assert!(load_time_zone("EST5").is_none());
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin"), Err(ZonedParseError::Cron(ParseError::Incomplete))));
+    assert!(matches!(ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 25 * * *"), Err(ZonedParseError::Cron(_))));
+    CronSchedule::parse_str("CRON_TZ=Europe/Berlin 0 9 * * *").expect_err("Should fail on prefix in plain schedule");
+}
+
+#[test]
+fn should_load_time_zone_from_tz_dir() {
+    let dir = std::env::temp_dir().join(format!("cronchik-tzdir-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("Europe")).unwrap();
+    std::fs::create_dir_all(dir.join("Test")).unwrap();
+    std::fs::write(dir.join("Test").join("Berlin"), tzdb::raw_tz_by_name("Europe/Berlin").unwrap()).unwrap();
+    std::fs::write(dir.join("Europe").join("Berlin"), b"not a time zone").unwrap();
+    std::env::set_var("TZDIR", &dir);
+
+    assert!(load_time_zone("Test/Berlin").is_some());
+    //Invalid file is skipped in favor of next directory or bundled database.
+    assert!(load_time_zone("Europe/Berlin").is_some());
+    assert!(load_time_zone("Test/Paris").is_none());
+
+    std::env::remove_var("TZDIR");
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_schedule_in_time_zone() {
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 9 * * *").unwrap();
+
+    let next = schedule.next_time_from(time::macros::datetime!(2026-01-10 00:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2026-01-10 08:00 UTC));
+    assert_eq!(next.offset(), time::macros::offset!(+1));
+
+    let next = schedule.next_time_from(time::macros::datetime!(2026-07-10 08:30 UTC));
+    assert_eq!(next, time::macros::datetime!(2026-07-11 07:00 UTC));
+    assert_eq!(next.offset(), time::macros::offset!(+2));
+
+    let schedule = ZonedSchedule::parse_str("TZ=America/New_York 0 0 9 * * * 2026").unwrap();
+    let next = schedule.next_time_from(time::macros::datetime!(2026-03-01 00:00 +09));
+    assert_eq!(next, time::macros::datetime!(2026-03-01 14:00 UTC));
+    assert_eq!(next.offset(), time::macros::offset!(-5));
+    assert!(schedule.try_next_time_from(time::macros::datetime!(2026-12-31 15:00 UTC)).is_none());
+
+    let schedule = ZonedSchedule::parse_str("0 9 * * *").unwrap();
+    let next = schedule.next_time_from(time::macros::datetime!(2026-01-10 10:00 +03));
+    assert_eq!(next, time::macros::datetime!(2026-01-10 09:00 UTC));
+    assert_eq!(next.offset(), time::UtcOffset::UTC);
+}
+
+#[test]
+fn should_schedule_across_dst_transitions() {
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 30 2 * * *").unwrap();
+
+    //02:30 doesn't exist on 2026-03-29
+    let next = schedule.next_time_from(time::macros::datetime!(2026-03-28 12:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2026-03-30 02:30 +02));
+
+    //02:30 happens twice on 2026-10-25
+    let next = schedule.next_time_from(time::macros::datetime!(2026-10-24 12:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2026-10-25 02:30 +02));
+    let next = schedule.next_time_from(next);
+    assert_eq!(next, time::macros::datetime!(2026-10-25 02:30 +01));
+    let next = schedule.next_time_from(next);
+    assert_eq!(next, time::macros::datetime!(2026-10-26 02:30 +01));
+
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 * * * *").unwrap();
+    let mut next = time::macros::datetime!(2026-03-29 00:30 UTC);
+    let expected = [
+        time::macros::datetime!(2026-03-29 01:00 UTC),
+        time::macros::datetime!(2026-03-29 02:00 UTC),
+        time::macros::datetime!(2026-03-29 03:00 UTC),
+    ];
+    for expected in expected.iter() {
+        next = schedule.next_time_from(next);
+        assert_eq!(next, *expected);
+    }
+}
+
+#[test]
+fn should_apply_gap_policy() {
+    let skip = DstPolicy::new().gap(GapPolicy::Skip);
+    let next_valid = DstPolicy::new().gap(GapPolicy::NextValid);
+    assert_eq!(DstPolicy::default(), skip);
+
+    //Europe/Berlin moves from 02:00 to 03:00 on 2026-03-29
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 30 2 * * *").unwrap();
+    let time = time::macros::datetime!(2026-03-28 12:00 UTC);
+
+    let schedule = schedule.with_dst_policy(skip);
+    assert_eq!(schedule.next_time_from(time), time::macros::datetime!(2026-03-30 02:30 +02));
+
+    let schedule = schedule.with_dst_policy(next_valid);
+    assert_eq!(schedule.dst_policy(), next_valid);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-03-29 03:00 +02));
+    assert_eq!(next.offset(), time::macros::offset!(+2));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-03-30 02:30 +02));
+
+    //Multiple times within gap are scheduled once, together with time at transition.
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0,30 2-3 * * *").unwrap().with_dst_policy(next_valid);
+    let mut next = time::macros::datetime!(2026-03-29 01:00 +01);
+    let expected = [
+        time::macros::datetime!(2026-03-29 01:00 UTC),
+        time::macros::datetime!(2026-03-29 01:30 UTC),
+        time::macros::datetime!(2026-03-30 00:00 UTC),
+    ];
+    for expected in expected.iter() {
+        next = schedule.next_time_from(next);
+        assert_eq!(next, *expected);
+    }
+
+    //America/New_York moves from 02:00 to 03:00 on 2026-03-08
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=America/New_York 30 2 * * *").unwrap();
+    let time = time::macros::datetime!(2026-03-07 12:00 UTC);
+
+    let schedule = schedule.with_dst_policy(skip);
+    assert_eq!(schedule.next_time_from(time), time::macros::datetime!(2026-03-09 02:30 -04));
+
+    let schedule = schedule.with_dst_policy(next_valid);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-03-08 03:00 -04));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-03-09 02:30 -04));
+
+    //Times outside of gap are not affected
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=America/New_York 0 9 * * *").unwrap().with_dst_policy(next_valid);
+    assert_eq!(schedule.next_time_from(time), time::macros::datetime!(2026-03-07 09:00 -05));
+    assert_eq!(schedule.next_time_from(time + time::Duration::days(1)), time::macros::datetime!(2026-03-08 09:00 -04));
+}
+
+#[test]
+fn should_apply_overlap_policy() {
+    let once = DstPolicy::new().overlap(OverlapPolicy::Once);
+    let twice = DstPolicy::new().overlap(OverlapPolicy::Twice);
+    assert_eq!(DstPolicy::default(), twice);
+
+    //Europe/Berlin moves from 03:00 to 02:00 on 2026-10-25
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 30 2 * * *").unwrap();
+    let time = time::macros::datetime!(2026-10-24 12:00 UTC);
+
+    let schedule = schedule.with_dst_policy(once);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-10-25 02:30 +02));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-10-26 02:30 +01));
+
+    let schedule = schedule.with_dst_policy(twice);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-10-25 02:30 +02));
+    let next = schedule.next_time_from(next);
+    assert_eq!(next, time::macros::datetime!(2026-10-25 02:30 +01));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-10-26 02:30 +01));
+
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 * * * *").unwrap();
+    let time = time::macros::datetime!(2026-10-25 00:30 +02);
+    let expected_once = [
+        time::macros::datetime!(2026-10-24 23:00 UTC),
+        time::macros::datetime!(2026-10-25 00:00 UTC),
+        time::macros::datetime!(2026-10-25 02:00 UTC),
+    ];
+    let expected_twice = [
+        time::macros::datetime!(2026-10-24 23:00 UTC),
+        time::macros::datetime!(2026-10-25 00:00 UTC),
+        time::macros::datetime!(2026-10-25 01:00 UTC),
+        time::macros::datetime!(2026-10-25 02:00 UTC),
+    ];
+    for (policy, expected) in [(once, &expected_once[..]), (twice, &expected_twice[..])].iter() {
+        let schedule = schedule.clone().with_dst_policy(*policy);
+        let mut next = time;
+        for expected in expected.iter() {
+            next = schedule.next_time_from(next);
+            assert_eq!(next, *expected);
+        }
+    }
+
+    //Starting within repeated local time
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin */20 2 * * *").unwrap();
+    let time = time::macros::datetime!(2026-10-25 02:10 +01);
+    assert_eq!(schedule.clone().with_dst_policy(once).next_time_from(time), time::macros::datetime!(2026-10-26 02:00 +01));
+    assert_eq!(schedule.with_dst_policy(twice).next_time_from(time), time::macros::datetime!(2026-10-25 02:20 +01));
+
+    //America/New_York moves from 02:00 to 01:00 on 2026-11-01
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=America/New_York 30 1 * * *").unwrap();
+    let time = time::macros::datetime!(2026-10-31 12:00 UTC);
+
+    let schedule = schedule.with_dst_policy(once);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-11-01 01:30 -04));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-11-02 01:30 -05));
+
+    let schedule = schedule.with_dst_policy(twice);
+    let next = schedule.next_time_from(time);
+    assert_eq!(next, time::macros::datetime!(2026-11-01 01:30 -04));
+    let next = schedule.next_time_from(next);
+    assert_eq!(next, time::macros::datetime!(2026-11-01 01:30 -05));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2026-11-02 01:30 -05));
+}
+
+#[test]
+fn should_schedule_sparse_in_time_zone() {
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 0 1 1 *").unwrap();
+    let mut next = time::macros::datetime!(2026-06-01 00:00 UTC);
+    for year in 2027..2060 {
+        next = schedule.next_time_from(next);
+        assert_eq!(next.date(), time::Date::from_calendar_date(year, time::Month::January, 1).unwrap());
+        assert_eq!(next.time(), time::Time::MIDNIGHT);
+        assert_eq!(next.offset(), time::macros::offset!(+1));
+    }
+
+    //Transitions after the end of time zone's table follow its rule.
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 0 0 0 1 7 * 2150").unwrap();
+    let next = schedule.next_time_from(time::macros::datetime!(2026-01-01 00:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2150-07-01 00:00 +02));
+
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Australia/Sydney 0 0 1 1,7 *").unwrap();
+    let next = schedule.next_time_from(time::macros::datetime!(2120-06-01 00:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2120-07-01 00:00 +10));
+    assert_eq!(schedule.next_time_from(next), time::macros::datetime!(2121-01-01 00:00 +11));
+
+    //Last Sunday of March 2150 is 29th
+    let schedule = ZonedSchedule::parse_str("CRON_TZ=Europe/Berlin 30 2 * * *").unwrap();
+    let next = schedule.next_time_from(time::macros::datetime!(2150-03-28 12:00 UTC));
+    assert_eq!(next, time::macros::datetime!(2150-03-30 02:30 +02));
+}